@@ -0,0 +1,124 @@
+//! Generates the registry of solutions that is compiled into the library, see `src/solutions/mod.rs`,
+//! and the example tests of every solution, see the `examples!` macro.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // solution binaries are named after their year and day, e.g. `src/bin/2025-01.rs`.
+    let mut puzzles: Vec<(u16, u8, String)> = fs::read_dir(bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+
+            if path.extension()? != "rs" {
+                return None;
+            }
+
+            let (year, day) = stem.split_once('-')?;
+
+            if year.len() != 4 || day.len() != 2 {
+                return None;
+            }
+
+            let year = year.parse::<u16>().ok()?;
+            let day = day
+                .parse::<u8>()
+                .ok()
+                .filter(|day| (1..=25).contains(day))?;
+            Some((year, day, path.to_str()?.to_string()))
+        })
+        .collect();
+
+    puzzles.sort_unstable();
+
+    let mut registry = String::new();
+
+    for (year, day, path) in &puzzles {
+        registry.push_str(&format!(
+            "#[path = {path:?}]\n#[allow(dead_code)]\nmod y{year}_{day:02};\n\n"
+        ));
+    }
+
+    registry.push_str("const SOLUTIONS: &[RegisteredSolution] = &[\n");
+    for (year, day, _) in &puzzles {
+        registry.push_str(&format!("    y{year}_{day:02}::SOLUTION,\n"));
+    }
+    registry.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+
+    let examples_dir = Path::new(&out_dir).join("examples");
+    fs::create_dir_all(&examples_dir).unwrap();
+
+    for (year, day, _) in &puzzles {
+        let data_dir = Path::new(&manifest_dir)
+            .join("data")
+            .join(year.to_string())
+            .join("examples");
+
+        println!("cargo:rerun-if-changed={}", data_dir.display());
+
+        let tests = example_tests(&data_dir, *day);
+        fs::write(examples_dir.join(format!("{year}-{day:02}.rs")), tests).unwrap();
+    }
+}
+
+/// Generates a test for every part that has an expected answer in the sidecar of an example,
+/// e.g. `data/2025/examples/07-b.answers` next to `data/2025/examples/07-b.txt`,
+/// and a test that checks the real input against the known answers in `data/answers.json`.
+fn example_tests(data_dir: &Path, day: u8) -> String {
+    let prefix = format!("{day:02}");
+
+    let mut examples: Vec<(String, String)> = fs::read_dir(data_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_stem()?.to_str()?.to_string();
+
+            // examples are either shared, e.g. `07.txt`, or per part, e.g. `07-1.txt`.
+            let is_of_day = name == prefix || name.starts_with(&format!("{prefix}-"));
+
+            if path.extension()? != "txt" || !is_of_day {
+                return None;
+            }
+
+            let sidecar = fs::read_to_string(path.with_extension("answers")).ok()?;
+            Some((name, sidecar))
+        })
+        .collect();
+
+    examples.sort_unstable();
+
+    let mut tests = String::from(
+        "#[test]\nfn test_known_answers() {\n    \
+        advent_of_code::template::answers::assert_known_answers(&crate::SOLUTION);\n}\n\n",
+    );
+
+    for (name, sidecar) in examples {
+        for line in sidecar.lines() {
+            let Some(part) = line
+                .split_once(':')
+                .and_then(|(key, _)| key.trim().strip_prefix("part_"))
+                .and_then(|part| part.parse::<u8>().ok())
+                .filter(|part| (1..=2).contains(part))
+            else {
+                continue;
+            };
+
+            let test_name = name.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+            tests.push_str(&format!(
+                "#[test]\nfn example_{test_name}_part_{part}() {{\n    \
+                advent_of_code::template::examples::assert_example(&crate::SOLUTION, {name:?}, {part});\n}}\n\n"
+            ));
+        }
+    }
+
+    tests
+}
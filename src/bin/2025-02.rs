@@ -1,4 +1,4 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2025, 2);
 
 const MAX_DIGITS: usize = 20;
 const MAX_BASE: usize = 10;
@@ -265,17 +265,5 @@ fn sum_progression_overlap(
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1_227_775_554));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4_174_379_265));
-    }
+    advent_of_code::examples!();
 }
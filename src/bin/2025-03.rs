@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(2025, 3);
 
 const MAX_DIGITS: usize = 12;
 
@@ -71,17 +71,5 @@ fn find_digit(input: &str, digit: u8, start: usize, end: usize) -> Option<usize>
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(357));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3121910778619));
-    }
+    advent_of_code::examples!();
 }
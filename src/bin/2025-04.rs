@@ -1,4 +1,4 @@
-advent_of_code::solution!(4);
+advent_of_code::solution!(2025, 4);
 
 const DIRS: [(i16, i16); 8] = [
     (-1, -1),
@@ -87,17 +87,5 @@ fn count_rolls(grid: &[Vec<char>], removed_grid: &mut [Vec<bool>]) -> (usize, us
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(43));
-    }
+    advent_of_code::examples!();
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(2025, 5);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let mut total: usize = 0;
@@ -60,17 +60,5 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
-    }
+    advent_of_code::examples!();
 }
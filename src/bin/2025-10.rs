@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 
-advent_of_code::solution!(10);
+use advent_of_code::template::Solution;
+
+advent_of_code::solution!(2025, 10, Day10);
 
 #[derive(Debug)]
-struct Machine {
+pub struct Machine {
     target: Vec<u8>,
     joltage: Vec<u64>,
     buttons: Vec<Vec<usize>>,
@@ -77,9 +79,7 @@ fn parse_machine(line: &str) -> Option<Machine> {
     })
 }
 
-fn solve_machine(input: &str) -> Option<u64> {
-    let machine = parse_machine(input)?;
-
+fn solve_machine(machine: &Machine) -> Option<u64> {
     if machine.buttons.is_empty() {
         return if machine.target.iter().all(|&t| t == 0) {
             Some(0)
@@ -98,26 +98,19 @@ fn solve_machine(input: &str) -> Option<u64> {
     )
 }
 
-fn solve_machine_with_joltage(input: &str) -> Option<u64> {
-    let machine = parse_machine(input)?;
-
+fn solve_machine_with_joltage(machine: &Machine) -> Result<u64, String> {
     if machine.buttons.is_empty() {
         return if machine.target.iter().all(|&t| t == 0) {
-            Some(0)
+            Ok(0)
         } else {
-            None
+            Err("machine has no buttons".into())
         };
     }
 
     let matrix = build_matrix(&machine.buttons, machine.num_lights);
+    let (x0, _) = gauss_eliminate(&matrix, &machine.joltage)?;
 
-    match gauss_eliminate(&matrix, &machine.joltage) {
-        Some((x0, _)) => Some(u64::try_from(l1_norm(&x0)).ok()?),
-        None => {
-            println!("No solution found for {:?}", machine);
-            None
-        }
-    }
+    u64::try_from(l1_norm(&x0)).map_err(|_| "solution has negative presses".into())
 }
 
 fn find_min_weight_solution(x0: &[u8], nullspace: &[Vec<u8>]) -> usize {
@@ -221,7 +214,7 @@ fn gauss_eliminate_gf2(a: &[Vec<u8>], b: &[u8]) -> Option<(Vec<u8>, Vec<Vec<u8>>
     Some((x0, nullspace))
 }
 
-fn gauss_eliminate(a: &[Vec<u8>], b: &[u64]) -> Option<(Vec<i64>, Vec<Vec<i64>>)> {
+fn gauss_eliminate(a: &[Vec<u8>], b: &[u64]) -> Result<(Vec<i64>, Vec<Vec<i64>>), String> {
     let m = a.len();
     let n = if m > 0 { a[0].len() } else { 0 };
 
@@ -266,21 +259,19 @@ fn gauss_eliminate(a: &[Vec<u8>], b: &[u64]) -> Option<(Vec<i64>, Vec<Vec<i64>>)
         }
 
         if aug[i][col] == 0 || sum % aug[i][col] != 0 {
-            println!("#1 No solution found for {:?}", aug);
-            return None;
+            return Err(format!("no integer solution for row {i}"));
         }
 
         x0[col] = sum / aug[i][col];
     }
 
-    for aug in aug.iter().take(m) {
+    for (i, aug) in aug.iter().take(m).enumerate() {
         let mut sum = 0;
         for j in 0..n {
             sum += aug[j] * x0[j];
         }
         if sum != aug[n] {
-            println!("#2 No solution found for {:?}", aug);
-            return None;
+            return Err(format!("particular solution does not satisfy row {i}"));
         }
     }
 
@@ -309,7 +300,7 @@ fn gauss_eliminate(a: &[Vec<u8>], b: &[u64]) -> Option<(Vec<i64>, Vec<Vec<i64>>)
         nullspace.push(v);
     }
 
-    Some((x0, nullspace))
+    Ok((x0, nullspace))
 }
 
 fn hamming_weight(vec: &[u8]) -> usize {
@@ -324,28 +315,50 @@ fn l1_norm(vec: &[i64]) -> i64 {
     vec.iter().map(|&x| x.abs()).sum()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    input.lines().map(solve_machine).sum()
-}
+pub struct Day10;
+
+impl Solution for Day10 {
+    // machines that fail to parse are kept, so that the sum of a part fails as a whole.
+    type Input = Vec<Option<Machine>>;
+    type Answer1 = Option<u64>;
+    type Answer2 = Result<u64, String>;
 
-pub fn part_two(input: &str) -> Option<u64> {
-    input.lines().map(solve_machine_with_joltage).sum()
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(parse_machine).collect()
+    }
+
+    fn part_one(machines: &Self::Input) -> Option<u64> {
+        machines
+            .iter()
+            .map(|machine| solve_machine(machine.as_ref()?))
+            .sum()
+    }
+
+    fn part_two(machines: &Self::Input) -> Result<u64, String> {
+        machines
+            .iter()
+            .enumerate()
+            .map(|(i, machine)| {
+                let machine = machine
+                    .as_ref()
+                    .ok_or_else(|| format!("could not parse machine {i}"))?;
+                solve_machine_with_joltage(machine).map_err(|e| format!("machine {i}: {e}"))
+            })
+            .sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
+    advent_of_code::examples!();
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = Day10::parse(&advent_of_code::template::read_file("examples", PUZZLE));
+        let result = Day10::part_two(&input);
         // TODO: Not solved yet Some(33)
-        assert_eq!(result, None);
+        assert_eq!(result.ok(), None);
     }
 }
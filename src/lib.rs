@@ -1,3 +1,18 @@
+// Allows solutions to refer to the library as `advent_of_code` when they are compiled into it.
+extern crate self as advent_of_code;
+
+pub mod solutions;
 pub mod template;
 
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[cfg(all(feature = "alloc-stats", feature = "dhat-heap"))]
+compile_error!("the `alloc-stats` and `dhat-heap` features both install a global allocator.");
+
+#[cfg(all(feature = "alloc-stats", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOC: template::alloc::CountingAlloc = template::alloc::CountingAlloc;
+
 // Use this file to add helper functions and additional modules.
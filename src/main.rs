@@ -1,79 +1,316 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    Error, all, download, examples, read, scaffold, solve, time, verify, watch_day,
+};
 use args::{AppArguments, parse};
 
-#[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
+use advent_of_code::template::PuzzleId;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        Day, Year,
+        commands::{scaffold, time::DEFAULT_THRESHOLD},
+        resources::Limits,
+        runner::RunOptions,
+    };
+    use pico_args::Arguments;
+    use std::{ffi::OsString, mem, process};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            year: Year,
+            day: Option<Day>,
+            missing: bool,
+            force: bool,
         },
         Read {
+            year: Year,
             day: Day,
         },
+        Examples {
+            year: Year,
+            day: Day,
+            force: bool,
+        },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
+            template: String,
+            answer_type: Option<String>,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             dhat: bool,
-            submit: Option<u8>,
+            /// Arguments that are passed on to the solution binary, see [`RunOptions`].
+            bin_args: Vec<String>,
         },
         All {
+            year: Year,
             release: bool,
+            in_process: bool,
+            jobs: usize,
+            limits: Limits,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            in_process: bool,
+            jobs: usize,
+            allow_parallel_timing: bool,
+            limits: Limits,
+        },
+        TimeHistory {
+            year: Year,
+            day: Day,
+        },
+        TimeCompare {
+            year: Year,
+            day: Option<Day>,
+            baseline: Option<String>,
+            threshold: f64,
+            in_process: bool,
+        },
+        SaveBaseline {
+            name: String,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
+            accept: bool,
+            release: bool,
+            in_process: bool,
+            jobs: usize,
+            limits: Limits,
+        },
+        WatchDay {
+            year: Year,
+            day: Day,
+            release: bool,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Reads the `--year` flag, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "pass `--year` or set `AOC_YEAR` to a valid year.".into()),
+        }
+    }
+
+    /// Reads the day of a puzzle, which has to be part of the year's calendar.
+    fn parse_day(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Day, Box<dyn std::error::Error>> {
+        let day = args.free_from_str()?;
+        check_day(year, day)
+    }
+
+    /// Reads an optional day of a puzzle, see [`parse_day`].
+    fn parse_opt_day(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Option<Day>, Box<dyn std::error::Error>> {
+        args.opt_free_from_str()?
+            .map(|day| check_day(year, day))
+            .transpose()
+    }
+
+    /// Reads the limits of each day, e.g. `--timeout 30s --memory-limit 2GiB`.
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, Box<dyn std::error::Error>> {
+        let timeout: Option<String> = args.opt_value_from_str("--timeout")?;
+        let memory: Option<String> = args.opt_value_from_str("--memory-limit")?;
+
+        Ok(Limits::parse(timeout.as_deref(), memory.as_deref())?)
+    }
+
+    fn check_day(year: Year, day: Day) -> Result<Day, Box<dyn std::error::Error>> {
+        if year.has_day(day) {
+            Ok(day)
+        } else {
+            Err(format!("{year} only has {} puzzles.", year.day_count()).into())
+        }
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        parse_from(Arguments::from_env())
+    }
 
+    fn parse_from(mut args: Arguments) -> Result<AppArguments, Box<dyn std::error::Error>> {
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
+                in_process: args.contains("--in-process"),
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+                limits: parse_limits(&mut args)?,
             },
             Some("time") => {
+                if let Some(day) = args.opt_value_from_str::<_, Day>("--history")? {
+                    let year = parse_year(&mut args)?;
+
+                    return Ok(AppArguments::TimeHistory {
+                        year,
+                        day: check_day(year, day)?,
+                    });
+                }
+
+                if let Some(name) = args.opt_value_from_str("--save-baseline")? {
+                    return Ok(AppArguments::SaveBaseline { name });
+                }
+
+                // compares against the latest stored timings, unless a named baseline is passed.
+                if args.contains("--compare") {
+                    let baseline = args.opt_value_from_str("--baseline")?;
+                    let threshold = args
+                        .opt_value_from_str("--threshold")?
+                        .unwrap_or(DEFAULT_THRESHOLD);
+                    let in_process = args.contains("--in-process");
+                    let year = parse_year(&mut args)?;
+
+                    return Ok(AppArguments::TimeCompare {
+                        year,
+                        day: parse_opt_day(&mut args, year)?,
+                        baseline,
+                        threshold,
+                        in_process,
+                    });
+                }
+
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let in_process = args.contains("--in-process");
+                let jobs = args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1);
+                let allow_parallel_timing = args.contains("--allow-parallel-timing");
+                let limits = parse_limits(&mut args)?;
+
+                let year = parse_year(&mut args)?;
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: parse_opt_day(&mut args, year)?,
                     store,
+                    in_process,
+                    jobs,
+                    allow_parallel_timing,
+                    limits,
+                }
+            }
+            Some("verify") => {
+                let accept = args.contains("--accept");
+                let release = args.contains("--release");
+                let in_process = args.contains("--in-process");
+                let jobs = args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1);
+                let limits = parse_limits(&mut args)?;
+
+                let year = parse_year(&mut args)?;
+
+                AppArguments::Verify {
+                    year,
+                    day: parse_opt_day(&mut args, year)?,
+                    accept,
+                    release,
+                    in_process,
+                    jobs,
+                    limits,
+                }
+            }
+            Some("download") => {
+                let missing = args.contains("--missing");
+                let force = args.contains("--force");
+
+                let year = parse_year(&mut args)?;
+
+                AppArguments::Download {
+                    year,
+                    day: parse_opt_day(&mut args, year)?,
+                    missing,
+                    force,
+                }
+            }
+            Some("read") => {
+                let year = parse_year(&mut args)?;
+
+                AppArguments::Read {
+                    year,
+                    day: parse_day(&mut args, year)?,
+                }
+            }
+            Some("examples") => {
+                let year = parse_year(&mut args)?;
+                let force = args.contains("--force");
+
+                AppArguments::Examples {
+                    year,
+                    day: parse_day(&mut args, year)?,
+                    force,
+                }
+            }
+            Some("scaffold") => {
+                let year = parse_year(&mut args)?;
+
+                AppArguments::Scaffold {
+                    year,
+                    day: parse_day(&mut args, year)?,
+                    download: args.contains("--download"),
+                    overwrite: args.contains("--overwrite"),
+                    template: args
+                        .opt_value_from_str("--template")?
+                        .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.to_string()),
+                    answer_type: args.opt_value_from_str("--answer-type")?,
+                }
+            }
+            Some("solve") => {
+                let year = parse_year(&mut args)?;
+                let day = parse_day(&mut args, year)?;
+                let release = args.contains("--release");
+                let dhat = args.contains("--dhat");
+
+                // the remaining arguments belong to the solution binary, check them before building it.
+                let bin_args: Vec<String> = mem::replace(&mut args, Arguments::from_vec(vec![]))
+                    .finish()
+                    .into_iter()
+                    .map(|arg| arg.to_string_lossy().into_owned())
+                    .collect();
+                let options = RunOptions::parse(bin_args.iter().map(OsString::from).collect())?;
+
+                let part_count = year.part_count(day);
+                if options
+                    .part
+                    .max(options.submit)
+                    .is_some_and(|part| part > part_count)
+                {
+                    return Err(format!("day {day} of {year} only has one part.").into());
+                }
+
+                AppArguments::Solve {
+                    year,
+                    day,
+                    release,
+                    dhat,
+                    bin_args,
+                }
+            }
+            Some("watch-day") => {
+                let year = parse_year(&mut args)?;
+                let release = args.contains("--release");
+
+                AppArguments::WatchDay {
+                    year,
+                    day: parse_day(&mut args, year)?,
+                    release,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -93,52 +330,153 @@ mod args {
 
         Ok(app_args)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use advent_of_code::day;
+        use pico_args::Arguments;
+        use std::ffi::OsString;
+
+        use super::{AppArguments, parse_from};
+
+        fn parse_args(args: &[&str]) -> AppArguments {
+            parse_from(Arguments::from_vec(
+                args.iter().map(OsString::from).collect(),
+            ))
+            .unwrap()
+        }
+
+        #[test]
+        fn parses_compare_with_day() {
+            assert!(matches!(
+                parse_args(&["time", "--compare", "1", "--year", "2025"]),
+                AppArguments::TimeCompare { day: Some(day), baseline: None, .. } if day == day!(1)
+            ));
+        }
+
+        #[test]
+        fn parses_compare_with_baseline() {
+            assert!(matches!(
+                parse_args(&["time", "--compare", "--baseline", "main", "--year", "2025"]),
+                AppArguments::TimeCompare { day: None, baseline: Some(name), .. } if name == "main"
+            ));
+        }
+    }
 }
 
 fn main() {
-    match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
+    let args = parse().unwrap_or_else(|err| {
+        eprintln!("Error: {err}");
+        process::exit(1);
+    });
+
+    if let Err(err) = run(args) {
+        eprintln!("Error: {err}");
+        process::exit(err.exit_code());
+    }
+}
+
+fn run(args: AppArguments) -> Result<(), Error> {
+    match args {
+        AppArguments::All {
+            year,
+            release,
+            in_process,
+            jobs,
+            limits,
+        } => all::handle(year, release, in_process, jobs, limits),
+        AppArguments::Time {
+            year,
+            day,
+            all,
+            store,
+            in_process,
+            jobs,
+            allow_parallel_timing,
+            limits,
+        } => time::handle(
+            year,
+            day,
+            all,
+            store,
+            in_process,
+            jobs,
+            allow_parallel_timing,
+            limits,
+        ),
+        AppArguments::TimeHistory { year, day } => time::history(PuzzleId::new(year, day)),
+        AppArguments::TimeCompare {
+            year,
+            day,
+            baseline,
+            threshold,
+            in_process,
+        } => time::compare(year, day, baseline.as_deref(), threshold, in_process),
+        AppArguments::SaveBaseline { name } => time::save_baseline(&name),
+        AppArguments::Verify {
+            year,
+            day,
+            accept,
+            release,
+            in_process,
+            jobs,
+            limits,
+        } => verify::handle(year, day, accept, release, in_process, jobs, limits),
+        AppArguments::Download {
+            year,
+            day,
+            missing,
+            force,
+        } => download::handle(year, day, missing, force),
+        AppArguments::Read { year, day } => read::handle(PuzzleId::new(year, day)),
+        AppArguments::Examples { year, day, force } => {
+            examples::handle(PuzzleId::new(year, day), force)
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
-                overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
-            }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
+        AppArguments::Scaffold {
+            year,
+            day,
+            download,
+            overwrite,
+            template,
+            answer_type,
+        } => {
+            // download first, so that the template can use the puzzle title.
+            if download {
+                try_download(PuzzleId::new(year, day));
             }
-        },
-    };
+            scaffold::handle(PuzzleId::new(year, day), overwrite, &template, answer_type)
+        }
+        AppArguments::Solve {
+            year,
+            day,
+            release,
+            dhat,
+            bin_args,
+        } => solve::handle(PuzzleId::new(year, day), release, dhat, &bin_args),
+        AppArguments::WatchDay { year, day, release } => {
+            watch_day::handle(PuzzleId::new(year, day), release)
+        }
+        #[cfg(feature = "today")]
+        AppArguments::Today => {
+            let puzzle = PuzzleId::today().ok_or_else(|| {
+                Error::Failed(
+                    "`today` command can only be run on a december day that has a puzzle \
+                        this year. Please use `scaffold` with a specific day."
+                        .into(),
+                )
+            })?;
+
+            try_download(puzzle);
+            scaffold::handle(puzzle, false, scaffold::DEFAULT_TEMPLATE, None)?;
+            read::handle(puzzle)
+        }
+    }
+}
+
+/// Downloads the puzzle before scaffolding it. A failed download does not keep the day from being
+/// scaffolded, it can be retried with `cargo download`.
+fn try_download(puzzle: PuzzleId) {
+    if let Err(e) = download::handle(puzzle.year, Some(puzzle.day), false, false) {
+        eprintln!("Warning: could not download {puzzle}, scaffolding it anyway: {e}");
+    }
 }
@@ -0,0 +1,28 @@
+//! Every solution in `src/bin` compiled into the library, so that days can be run in-process.
+//!
+//! ```ignore
+//! use advent_of_code::{day, template::{PuzzleId, context::Context}, year};
+//!
+//! let solution = advent_of_code::solutions::get(PuzzleId::new(year!(2025), day!(1))).unwrap();
+//! let answer = solution.part(1).map(|part_one| part_one("L68\nR48", &Context::real()));
+//! ```
+use crate::template::{PuzzleId, registry::RegisteredSolution};
+
+// the registry is generated by `build.rs`. Solutions are tested through their own binaries,
+// so they are left out of the library's test build to avoid running their tests twice.
+// The registry itself is tested against the regular build in `tests/solutions.rs`.
+#[cfg(not(test))]
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+#[cfg(test)]
+const SOLUTIONS: &[RegisteredSolution] = &[];
+
+/// Returns the solution registered for a puzzle, if the puzzle has been scaffolded.
+pub fn get(puzzle: PuzzleId) -> Option<&'static RegisteredSolution> {
+    SOLUTIONS.iter().find(|solution| solution.puzzle == puzzle)
+}
+
+/// Returns every registered solution, ordered by year and day.
+pub fn all() -> &'static [RegisteredSolution] {
+    SOLUTIONS
+}
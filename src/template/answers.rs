@@ -0,0 +1,295 @@
+/// Answers that were confirmed to be correct, used to catch regressions in solutions.
+use std::{collections::HashMap, env, fs, io::Error, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    Day, PartOutput, PuzzleId, Year, context::Context, registry::RegisteredSolution,
+    year::parse_year,
+};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Represents the known answers for a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub year: Year,
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the known answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns no answers.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(ANSWERS_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(|s| Answers::try_from(s.as_str()))
+            .unwrap_or_default()
+    }
+
+    /// Returns the known answer for a part, if any.
+    pub fn get(&self, puzzle: PuzzleId, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.puzzle() == puzzle)?;
+
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Records the answer for a part, replacing a previously known answer.
+    pub fn set(&mut self, puzzle: PuzzleId, part: u8, value: &str) {
+        let index = match self.data.iter().position(|a| a.puzzle() == puzzle) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    year: puzzle.year,
+                    day: puzzle.day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(Answer::puzzle);
+                self.data.iter().position(|a| a.puzzle() == puzzle).unwrap()
+            }
+        };
+
+        let answer = &mut self.data[index];
+
+        match part {
+            1 => answer.part_1 = Some(value.into()),
+            2 => answer.part_2 = Some(value.into()),
+            _ => {}
+        }
+    }
+}
+
+impl Answer {
+    pub fn puzzle(&self) -> PuzzleId {
+        PuzzleId::new(self.year, self.day)
+    }
+}
+
+/// Asserts that a part solves the real input of a puzzle to its known answer.
+///
+/// Does nothing if either the input file or the known answer is missing, so tests using this helper
+/// pass in checkouts that don't contain puzzle inputs.
+pub fn assert_known_answer<R: PartOutput>(
+    puzzle: PuzzleId,
+    part: u8,
+    solve: impl FnOnce(&str) -> R,
+) {
+    check_known_answer(puzzle, part, |input, context| {
+        context.run(|| solve(input).to_answer())
+    });
+}
+
+/// Asserts that every part of a solution solves the real input to its known answer, see [`assert_known_answer`].
+/// This is called by the test that [`examples!`](crate::examples) generates.
+pub fn assert_known_answers(solution: &RegisteredSolution) {
+    for (part, solve) in solution.parts {
+        check_known_answer(solution.puzzle, *part, solve);
+    }
+}
+
+fn check_known_answer(
+    puzzle: PuzzleId,
+    part: u8,
+    solve: impl FnOnce(&str, &Context) -> Result<Option<String>, String>,
+) {
+    let path = env::current_dir()
+        .unwrap()
+        .join("data")
+        .join(puzzle.year.to_string())
+        .join("inputs")
+        .join(format!("{}.txt", puzzle.day));
+
+    let Ok(input) = fs::read_to_string(&path) else {
+        eprintln!("Skipping {puzzle} part {part}: no input file.");
+        return;
+    };
+
+    let answers = Answers::read_from_file();
+
+    let Some(expected) = answers.get(puzzle, part) else {
+        eprintln!("Skipping {puzzle} part {part}: no known answer.");
+        return;
+    };
+
+    let result = solve(&input, &Context::real())
+        .unwrap_or_else(|e| panic!("{puzzle} part {part} failed: {e}"));
+    assert_eq!(
+        result.as_deref(),
+        Some(expected),
+        "{puzzle} part {part} does not match its known answer"
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for Answers {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).map_err(|x| x.to_string())?;
+        let json_data: &HashMap<String, JsonValue> =
+            json.get().ok_or("Expected answers to be a JSON object.")?;
+
+        Ok(Answers {
+            data: json_data
+                .get("data")
+                .ok_or("Expected answers to have key \"data\".")?
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected answers.data to be an array.")?
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let year = parse_year(json, "answer")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            year,
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::Answers;
+    use crate::{
+        day,
+        template::{Day, PuzzleId},
+        year,
+    };
+
+    fn puzzle(day: Day) -> PuzzleId {
+        PuzzleId::new(year!(2025), day)
+    }
+
+    #[test]
+    fn sets_and_gets_answers() {
+        let mut answers = Answers::default();
+
+        answers.set(puzzle(day!(9)), 2, "1525241870");
+        answers.set(puzzle(day!(2)), 1, "1227775554");
+        answers.set(puzzle(day!(9)), 1, "50");
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(2));
+        assert_eq!(answers.get(puzzle(day!(9)), 1), Some("50"));
+        assert_eq!(answers.get(puzzle(day!(9)), 2), Some("1525241870"));
+        assert_eq!(answers.get(puzzle(day!(2)), 2), None);
+        assert_eq!(answers.get(puzzle(day!(3)), 1), None);
+    }
+
+    #[test]
+    fn overwrites_answers() {
+        let mut answers = Answers::default();
+        answers.set(puzzle(day!(1)), 1, "3");
+        answers.set(puzzle(day!(1)), 1, "4");
+        assert_eq!(answers.get(puzzle(day!(1)), 1), Some("4"));
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.set(puzzle(day!(1)), 1, "3");
+        answers.set(puzzle(day!(5)), 2, "14");
+
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json.as_str()), Ok(answers));
+    }
+
+    #[test]
+    fn rejects_invalid_answers() {
+        assert!(Answers::try_from("{\"data\":[{\"day\":\"01\",\"part_1\":3}]}").is_err());
+        assert!(Answers::try_from("{}").is_err());
+    }
+}
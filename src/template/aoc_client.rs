@@ -0,0 +1,522 @@
+/// A client for the Advent of Code website, used to download inputs and puzzles and to submit answers.
+use std::{
+    cell::Cell,
+    env,
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+use ureq::Agent;
+
+use crate::template::{PuzzleId, submissions::Verdict};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Minimum time between two requests, to avoid hammering the website when downloading in bulk.
+const DEFAULT_REQUEST_DELAY: Duration = Duration::from_secs(2);
+static USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/xikxp1/aoc_2025)"
+);
+
+#[derive(Debug)]
+pub enum AocError {
+    /// No session cookie was found, or the website did not accept it.
+    NotLoggedIn,
+    /// The puzzle is not unlocked yet.
+    NotUnlocked,
+    /// The website asks to wait before submitting another answer.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The submitted answer is wrong, with the hint given by the website.
+    WrongAnswer(Verdict),
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+    BadStatus(u16),
+    /// The website responded with a message the client does not understand.
+    UnexpectedResponse(String),
+    Http(ureq::Error),
+    IO(io::Error),
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::NotLoggedIn => write!(
+                f,
+                "not logged in. Store your session cookie in the ADVENT_OF_CODE_SESSION environment variable \
+                or in ~/.adventofcode.session."
+            ),
+            AocError::NotUnlocked => write!(f, "the puzzle is not unlocked yet."),
+            AocError::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {wait} before submitting again.")
+            }
+            AocError::RateLimited { wait: None } => {
+                write!(f, "rate limited, wait before submitting again.")
+            }
+            AocError::WrongAnswer(Verdict::TooHigh) => {
+                write!(f, "that's not the right answer, it is too high.")
+            }
+            AocError::WrongAnswer(Verdict::TooLow) => {
+                write!(f, "that's not the right answer, it is too low.")
+            }
+            AocError::WrongAnswer(_) => write!(f, "that's not the right answer."),
+            AocError::WrongLevel => write!(
+                f,
+                "the part is not the current level, did you already solve it?"
+            ),
+            AocError::BadStatus(status) => write!(f, "the website responded with status {status}."),
+            AocError::UnexpectedResponse(message) => {
+                write!(
+                    f,
+                    "unexpected response from the website: {}",
+                    message.trim()
+                )
+            }
+            AocError::Http(e) => write!(f, "request failed: {e}"),
+            AocError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocError {
+    fn from(e: ureq::Error) -> Self {
+        AocError::Http(e)
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::IO(e)
+    }
+}
+
+pub struct AocClient {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    delay: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent: Agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.into(),
+            delay: DEFAULT_REQUEST_DELAY,
+            last_request: Cell::new(None),
+        }
+    }
+
+    /// Sets the minimum time between two requests.
+    #[must_use]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Creates a client that is authenticated with the stored session cookie.
+    /// `AOC_BASE_URL` overrides the address of the website, e.g. to point the client at a mock server.
+    pub fn from_env() -> Result<Self, AocError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &read_session()?))
+    }
+
+    /// Downloads the input of a puzzle.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocError> {
+        self.get(&format!("{}/input", self.puzzle_url(puzzle)))
+    }
+
+    /// Downloads the description of a puzzle as markdown.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocError> {
+        self.get(&self.puzzle_url(puzzle))
+            .map(|html| html_to_markdown(&html))
+    }
+
+    /// Submits an answer, returning the message of the website if the answer was right.
+    pub fn submit(&self, puzzle: PuzzleId, part: u8, answer: &str) -> Result<String, AocError> {
+        self.throttle();
+
+        let mut response = self
+            .agent
+            .post(format!("{}/answer", self.puzzle_url(puzzle)))
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])?;
+
+        check_status(response.status().as_u16())?;
+
+        let message = html_to_markdown(&response.body_mut().read_to_string()?);
+
+        if message.contains("You gave an answer too recently") {
+            return Err(AocError::RateLimited {
+                wait: parse_wait(&message),
+            });
+        }
+
+        if message.contains("You don't seem to be solving the right level") {
+            return Err(AocError::WrongLevel);
+        }
+
+        match Verdict::from_response(&message) {
+            Some(Verdict::Correct) => Ok(message),
+            Some(verdict) => Err(AocError::WrongAnswer(verdict)),
+            None => Err(AocError::UnexpectedResponse(message)),
+        }
+    }
+
+    fn puzzle_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocError> {
+        self.throttle();
+
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", format!("session={}", self.session))
+            .call()?;
+
+        check_status(response.status().as_u16())?;
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    /// Waits until the minimum delay since the previous request has passed.
+    fn throttle(&self) {
+        if let Some(last_request) = self.last_request.get() {
+            thread::sleep(self.delay.saturating_sub(last_request.elapsed()));
+        }
+
+        self.last_request.set(Some(Instant::now()));
+    }
+}
+
+fn check_status(status: u16) -> Result<(), AocError> {
+    match status {
+        200..=299 => Ok(()),
+        400 | 401 | 403 => Err(AocError::NotLoggedIn),
+        404 => Err(AocError::NotUnlocked),
+        429 => Err(AocError::RateLimited { wait: None }),
+        status => Err(AocError::BadStatus(status)),
+    }
+}
+
+/// Reads the session cookie from the same places as aoc-cli: the `ADVENT_OF_CODE_SESSION` environment variable,
+/// `~/.adventofcode.session` or `adventofcode.session` in the config directory.
+fn read_session() -> Result<String, AocError> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION")
+        && !session.trim().is_empty()
+    {
+        return Ok(session.trim().into());
+    }
+
+    let home = env::var("HOME").map(PathBuf::from).ok();
+    let config = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .ok()
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+    [
+        home.map(|home| home.join(".adventofcode.session")),
+        config.map(|config| config.join("adventofcode.session")),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|path| fs::read_to_string(path).ok())
+    .map(|session| session.trim().to_string())
+    .find(|session| !session.is_empty())
+    .ok_or(AocError::NotLoggedIn)
+}
+
+/// Extracts the time to wait from a rate limit message, e.g. "you have 40s left to wait".
+fn parse_wait(message: &str) -> Option<String> {
+    let (_, rest) = message.split_once("you have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    Some(wait.into())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Converts the `<article>` elements of a page to markdown.
+/// Only handles the handful of tags used on puzzle pages, other tags are dropped and their text kept.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let article = &rest[start..];
+        let end = article.find("</article>").unwrap_or(article.len());
+        convert_article(&article[..end], &mut markdown);
+        rest = &article[end..];
+    }
+
+    let trimmed = markdown.trim_end();
+    if trimmed.is_empty() {
+        String::new()
+    } else {
+        format!("{trimmed}\n")
+    }
+}
+
+fn convert_article(html: &str, out: &mut String) {
+    let mut rest = html;
+    let mut is_pre = false;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            out.push_str(&decode_entities(rest));
+            break;
+        };
+
+        out.push_str(&decode_entities(&rest[..start]));
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                is_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                is_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !is_pre => out.push('`'),
+            ("em", _) if !is_pre => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            ("br", _) => out.push('\n'),
+            _ => {}
+        }
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
+    };
+
+    use super::{AocClient, AocError, html_to_markdown};
+    use crate::{
+        day,
+        template::{Day, PuzzleId, submissions::Verdict},
+        year,
+    };
+
+    fn puzzle(day: Day) -> PuzzleId {
+        PuzzleId::new(year!(2025), day)
+    }
+
+    /// Serves the given responses to consecutive requests, returning the requests that were received.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some((key, value)) = line.split_once(':')
+                        && key.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+
+                    request.push_str(&line);
+
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+
+                let mut body_bytes = vec![0; content_length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request.push_str(&String::from_utf8_lossy(&body_bytes));
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (address, handle)
+    }
+
+    #[test]
+    fn downloads_inputs() {
+        let (address, handle) = serve(vec![(200, "1\n2\n3\n")]);
+        let client = AocClient::new(&address, "abc").with_delay(Duration::ZERO);
+
+        assert_eq!(client.input(puzzle(day!(3))).unwrap(), "1\n2\n3\n");
+
+        let requests = handle.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/3/input HTTP/1.1"));
+        assert!(requests[0].to_lowercase().contains("cookie: session=abc"));
+    }
+
+    #[test]
+    fn downloads_puzzles() {
+        let (address, handle) = serve(vec![(
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 3 ---</h2><p>Hello <em>world</em>.</p></article></main>",
+        )]);
+        let client = AocClient::new(&address, "abc").with_delay(Duration::ZERO);
+
+        assert_eq!(
+            client.puzzle(puzzle(day!(3))).unwrap(),
+            "## --- Day 3 ---\n\nHello *world*.\n"
+        );
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn delays_requests() {
+        let (address, handle) = serve(vec![(200, "1"), (200, "2")]);
+        let client = AocClient::new(&address, "abc").with_delay(Duration::from_millis(200));
+
+        let timer = Instant::now();
+        client.input(puzzle(day!(1))).unwrap();
+        client.input(puzzle(day!(2))).unwrap();
+
+        assert!(timer.elapsed() >= Duration::from_millis(200));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn maps_status_codes() {
+        let (address, handle) = serve(vec![(400, "Please log in"), (404, "Not found")]);
+        let client = AocClient::new(&address, "abc").with_delay(Duration::ZERO);
+
+        assert!(matches!(
+            client.input(puzzle(day!(3))),
+            Err(AocError::NotLoggedIn)
+        ));
+        assert!(matches!(
+            client.input(puzzle(day!(4))),
+            Err(AocError::NotUnlocked)
+        ));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (address, handle) = serve(vec![
+            (200, "<article><p>That's the right answer!</p></article>"),
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>You gave an answer too recently; you have 40s left to wait.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>You don't seem to be solving the right level.</p></article>",
+            ),
+        ]);
+        let client = AocClient::new(&address, "abc").with_delay(Duration::ZERO);
+
+        assert_eq!(
+            client.submit(puzzle(day!(5)), 2, "42").unwrap(),
+            "That's the right answer!\n"
+        );
+        assert!(matches!(
+            client.submit(puzzle(day!(5)), 2, "43"),
+            Err(AocError::WrongAnswer(Verdict::TooHigh))
+        ));
+        assert!(matches!(
+            client.submit(puzzle(day!(5)), 2, "44"),
+            Err(AocError::RateLimited { wait: Some(wait) }) if wait == "40s"
+        ));
+        assert!(matches!(
+            client.submit(puzzle(day!(5)), 2, "45"),
+            Err(AocError::WrongLevel)
+        ));
+
+        let requests = handle.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/5/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn converts_articles_to_markdown() {
+        let html = "<body><article class=\"day-desc\"><h2>--- Day 1: Test ---</h2>\
+            <p>Read the <a href=\"/x\">input</a> &amp; find <code>x &lt; 3</code>:</p>\
+            <pre><code>1\n<em>2</em>\n</code></pre>\
+            <ul><li>one</li><li>two</li></ul></article>\
+            <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>More.</p></article></body>";
+
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1: Test ---\n\nRead the input & find `x < 3`:\n\n```\n1\n2\n```\n\n- one\n- two\n\n\
+            ## --- Part Two ---\n\nMore.\n"
+        );
+    }
+}
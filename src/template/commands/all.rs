@@ -1,5 +1,33 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{
+    Year, all_days,
+    answers::Answers,
+    resources::Limits,
+    run_multi::{MultiOptions, run_multi},
+};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use super::{Error, check_days};
+
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    is_in_process: bool,
+    jobs: usize,
+    limits: Limits,
+) -> Result<(), Error> {
+    let options = MultiOptions {
+        is_release,
+        is_timed: false,
+        is_in_process,
+        jobs,
+        allow_parallel_timing: false,
+        limits,
+    };
+
+    let run = run_multi(
+        year,
+        &all_days(year).collect(),
+        &options,
+        &Answers::read_from_file(),
+    )?;
+    check_days(run.days.iter().map(|day| day.status))
 }
@@ -1,16 +1,119 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
-
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
+use crate::template::{
+    Day, PuzzleId, Year, all_days,
+    aoc_client::{AocClient, AocError},
+};
+use std::{fs, path::Path};
+
+use super::Error;
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+pub fn handle(year: Year, day: Option<Day>, missing: bool, force: bool) -> Result<(), Error> {
+    let client = AocClient::from_env()
+        .map_err(|e| Error::Failed(format!("failed to create client: {e}")))?;
+
+    let puzzles: Vec<PuzzleId> = match day {
+        Some(day) => vec![PuzzleId::new(year, day)],
+        None if missing => all_days(year)
+            .map(|day| PuzzleId::new(year, day))
+            .filter(|puzzle| puzzle.day.is_unlocked(year) && !has_input(*puzzle))
+            .collect(),
+        None => {
+            return Err(Error::Failed(
+                "specify a day or pass `--missing` to download all missing inputs.".into(),
+            ));
+        }
     };
+
+    if puzzles.is_empty() {
+        println!("🎄 All inputs are present.");
+        return Ok(());
+    }
+
+    for puzzle in puzzles {
+        match download(&client, puzzle, missing, force) {
+            Ok(()) => {}
+            // days of a bulk download are in order, so none of the following days are unlocked either.
+            Err(AocError::NotUnlocked) if missing => {
+                println!("Day {} is not unlocked yet.", puzzle.day);
+                break;
+            }
+            Err(e) => return Err(Error::Failed(format!("failed to download {puzzle}: {e}"))),
+        }
+    }
+
+    Ok(())
+}
+
+/// Downloads the input and the puzzle description of a day. With `missing` set, an existing description is
+/// kept, so that bulk downloads only request what they lack.
+fn download(
+    client: &AocClient,
+    puzzle: PuzzleId,
+    missing: bool,
+    force: bool,
+) -> Result<(), AocError> {
+    let input_path = input_path(puzzle);
+    let puzzle_dir = format!("data/{}/puzzles", puzzle.year);
+    let puzzle_path = puzzle_path(puzzle);
+
+    if has_input(puzzle) && !force {
+        println!("Input \"{input_path}\" already exists, pass `--force` to overwrite it.");
+    } else {
+        let input = cached_input(client, puzzle, force)?;
+        fs::create_dir_all(format!("data/{}/inputs", puzzle.year))?;
+        fs::write(&input_path, input)?;
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+
+    if missing && has_puzzle(puzzle) && !force {
+        return Ok(());
+    }
+
+    // the puzzle is fetched again otherwise, as the second part is only included once the first part is solved.
+    let description = client.puzzle(puzzle)?;
+    fs::create_dir_all(&puzzle_dir)?;
+    fs::write(&puzzle_path, description)?;
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+
+    Ok(())
+}
+
+/// Reads an input from the local cache, downloading it if it's not cached or `force` is set.
+/// Inputs never change once unlocked, so the cache is keyed by year and day.
+fn cached_input(client: &AocClient, puzzle: PuzzleId, force: bool) -> Result<String, AocError> {
+    let cache_dir = format!("data/cache/{}", puzzle.year);
+    let cache_path = format!("{cache_dir}/{}.txt", puzzle.day);
+
+    if !force
+        && let Ok(input) = fs::read_to_string(&cache_path)
+        && !input.is_empty()
+    {
+        return Ok(input);
+    }
+
+    let input = client.input(puzzle)?;
+    fs::create_dir_all(&cache_dir)?;
+    fs::write(&cache_path, &input)?;
+    Ok(input)
+}
+
+fn input_path(puzzle: PuzzleId) -> String {
+    format!("data/{}/inputs/{}.txt", puzzle.year, puzzle.day)
+}
+
+fn puzzle_path(puzzle: PuzzleId) -> String {
+    format!("data/{}/puzzles/{}.md", puzzle.year, puzzle.day)
+}
+
+/// Checks whether a non-empty input file exists for a puzzle.
+fn has_input(puzzle: PuzzleId) -> bool {
+    is_non_empty(&input_path(puzzle))
+}
+
+/// Checks whether a non-empty description exists for a puzzle.
+fn has_puzzle(puzzle: PuzzleId) -> bool {
+    is_non_empty(&puzzle_path(puzzle))
+}
+
+fn is_non_empty(path: &str) -> bool {
+    Path::new(path).metadata().is_ok_and(|m| m.len() > 0)
 }
@@ -0,0 +1,43 @@
+use std::fs;
+
+use crate::template::{PuzzleId, examples::Examples};
+
+use super::Error;
+
+pub fn handle(puzzle: PuzzleId, force: bool) -> Result<(), Error> {
+    let PuzzleId { year, day } = puzzle;
+    let description_path = format!("data/{year}/puzzles/{day}.md");
+
+    let Ok(description) = fs::read_to_string(&description_path) else {
+        return Err(Error::Failed(format!(
+            "failed to read \"{description_path}\", run `cargo download {day} --year {year}` first."
+        )));
+    };
+
+    let examples = Examples::parse(&description);
+
+    if examples.part_1.is_none() {
+        return Err(Error::Failed(format!(
+            "found no example in \"{description_path}\"."
+        )));
+    }
+
+    for (part, example) in [(1, &examples.part_1), (2, &examples.part_2)] {
+        let answer = example.as_ref().and_then(|e| e.answer.as_deref());
+        println!("Part {part}: {}", answer.unwrap_or("-"));
+    }
+
+    match examples.write_files(puzzle, force) {
+        Ok(paths) if paths.is_empty() => {
+            println!("Example files already exist, pass `--force` to overwrite them.");
+        }
+        Ok(paths) => {
+            for path in paths {
+                println!("🎄 Successfully wrote \"{path}\".");
+            }
+        }
+        Err(e) => return Err(Error::Failed(format!("failed to write example: {e}"))),
+    }
+
+    Ok(())
+}
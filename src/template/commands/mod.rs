@@ -1,6 +1,97 @@
+/// Subcommands of the `cargo` aliases. Every command returns an [`Error`] instead of exiting itself,
+/// `main` prints it and exits with [`Error::exit_code`].
+use std::{fmt::Display, process::ExitStatus};
+
+use super::run_multi::DayStatus;
+
 pub mod all;
 pub mod download;
+pub mod examples;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+pub mod verify;
+pub mod watch_day;
+
+/// Why a command did not succeed.
+#[derive(Debug)]
+pub enum Error {
+    /// The command could not do its work, e.g. because a file could not be written.
+    Failed(String),
+    /// A child process exited unsuccessfully, its own output already tells why.
+    Child { command: String, status: ExitStatus },
+    /// Some of the selected days did not succeed, they are listed in the summary of the run.
+    Days { failed: usize, total: usize },
+}
+
+impl Error {
+    /// The exit code of the process, which is the one of the child for [`Error::Child`].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Child { status, .. } => status.code().filter(|code| *code != 0).unwrap_or(1),
+            Error::Failed(_) | Error::Days { .. } => 1,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Failed(message) => f.write_str(message),
+            Error::Child { command, status } => write!(f, "`{command}` exited with {status}."),
+            Error::Days { failed, total } => {
+                write!(f, "{failed} of {total} day(s) did not succeed.")
+            }
+        }
+    }
+}
+
+impl From<super::run_multi::Error> for Error {
+    fn from(e: super::run_multi::Error) -> Self {
+        Error::Failed(e.to_string())
+    }
+}
+
+/// Fails if any of the days did not succeed, see [`DayStatus::is_success`].
+fn check_days(statuses: impl IntoIterator<Item = DayStatus>) -> Result<(), Error> {
+    let (total, failed) = statuses
+        .into_iter()
+        .fold((0, 0), |(total, failed), status| {
+            (total + 1, failed + usize::from(!status.is_success()))
+        });
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(Error::Days { failed, total })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Error, check_days};
+    use crate::template::run_multi::DayStatus;
+
+    #[test]
+    fn fails_if_any_day_did_not_succeed() {
+        assert!(check_days([DayStatus::Solved, DayStatus::Unsolved]).is_ok());
+        assert!(check_days([]).is_ok());
+
+        let res = check_days([
+            DayStatus::Solved,
+            DayStatus::Panicked,
+            DayStatus::Mismatched,
+            DayStatus::Failed,
+        ]);
+        assert!(matches!(
+            res,
+            Err(Error::Days {
+                failed: 3,
+                total: 4
+            })
+        ));
+    }
+}
@@ -1,17 +1,26 @@
-use std::process;
+use std::fs;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{PuzzleId, aoc_client::AocClient};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+use super::Error;
+
+pub fn handle(puzzle: PuzzleId) -> Result<(), Error> {
+    let client = AocClient::from_env()
+        .map_err(|e| Error::Failed(format!("failed to create client: {e}")))?;
+
+    let description = client
+        .puzzle(puzzle)
+        .map_err(|e| Error::Failed(format!("failed to read {puzzle}: {e}")))?;
+
+    let puzzle_dir = format!("data/{}/puzzles", puzzle.year);
+    let puzzle_path = format!("{puzzle_dir}/{}.md", puzzle.day);
+
+    if let Err(e) =
+        fs::create_dir_all(&puzzle_dir).and_then(|()| fs::write(puzzle_path, &description))
+    {
+        eprintln!("Failed to store puzzle: {e}");
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    println!("{description}");
+    Ok(())
 }
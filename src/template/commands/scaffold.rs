@@ -1,13 +1,215 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
+    fmt::Display,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
 };
 
-use crate::template::Day;
+use crate::template::{PuzzleId, examples::Examples};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use super::Error;
+
+/// Directory of the module templates. Templates are read at runtime, so new ones don't need a rebuild.
+const TEMPLATE_DIR: &str = "templates";
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+const DEFAULT_ANSWER_TYPE: &str = "u64";
+
+/// Prefix of the header lines that configure a template, e.g. `//@ answer_type: String`.
+/// Header lines are not copied to the module.
+const HEADER_PREFIX: &str = "//@";
+
+/// Header lines that enclose code of the second part, which is left out for puzzles with a single part.
+const PART_TWO_START: &str = "part_two";
+const PART_TWO_END: &str = "end";
+
+/* -------------------------------------------------------------------------- */
+
+/// A module template, loaded from `templates/{name}.txt`.
+///
+/// Supported placeholders:
+/// - `%YEAR%` and `%DAY_NUMBER%`, e.g. `2025` and `7`
+/// - `%DAY_PADDED%`, the two digit day, e.g. `07`
+/// - `%DAY_TITLE%`, e.g. `Day 7: Laboratories`, or `Day 7` if the puzzle was not downloaded yet
+/// - `%ANSWER_TYPE%`, `u64` unless set by the template header or `--answer-type`
+/// - `%EXAMPLE_ANSWER_1%` and `%EXAMPLE_ANSWER_2%`, the expected example answers as an `Option`
+/// - `%PARTS%`, `, 1` for puzzles with a single part, so that `solution!` only registers the first part
+///
+/// Code between `//@ part_two` and `//@ end` lines is only kept for puzzles with two parts, see [`Year::part_count`](crate::template::Year::part_count).
+pub struct Template {
+    contents: String,
+    /// The contents without the code of the second part.
+    single_part_contents: String,
+    answer_type: Option<String>,
+}
+
+/// Values for the placeholders of a [`Template`].
+pub struct Placeholders {
+    pub puzzle: PuzzleId,
+    pub title: Option<String>,
+    pub answer_type: Option<String>,
+    pub example_answers: [Option<String>; 2],
+}
+
+#[derive(Debug)]
+pub enum TemplateError {
+    Unknown {
+        name: String,
+        available: Vec<String>,
+    },
+    IO(io::Error),
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::Unknown { name, available } if available.is_empty() => {
+                write!(
+                    f,
+                    "unknown template `{name}`, no templates found in `{TEMPLATE_DIR}/`."
+                )
+            }
+            TemplateError::Unknown { name, available } => write!(
+                f,
+                "unknown template `{name}`, expected one of: {}.",
+                available.join(", ")
+            ),
+            TemplateError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Template {
+    /// Loads a template by its name.
+    pub fn load(name: &str) -> Result<Self, TemplateError> {
+        let path = Path::new(TEMPLATE_DIR).join(format!("{name}.txt"));
+
+        match fs::read_to_string(path) {
+            Ok(s) => Ok(Self::parse(&s)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(TemplateError::Unknown {
+                name: name.into(),
+                available: available_templates(),
+            }),
+            Err(e) => Err(TemplateError::IO(e)),
+        }
+    }
+
+    fn parse(s: &str) -> Self {
+        let mut answer_type = None;
+        let mut contents = String::with_capacity(s.len());
+        let mut single_part_contents = String::with_capacity(s.len());
+        let mut is_part_two = false;
+
+        for line in s.split_inclusive('\n') {
+            match line.strip_prefix(HEADER_PREFIX).map(str::trim) {
+                Some(PART_TWO_START) => is_part_two = true,
+                Some(PART_TWO_END) => is_part_two = false,
+                Some(header) => {
+                    if let Some((key, value)) = header.split_once(':')
+                        && key.trim() == "answer_type"
+                    {
+                        answer_type = Some(value.trim().to_string());
+                    }
+                }
+                None => {
+                    contents.push_str(line);
+                    if !is_part_two {
+                        single_part_contents.push_str(line);
+                    }
+                }
+            }
+        }
+
+        Self {
+            contents,
+            single_part_contents,
+            answer_type,
+        }
+    }
+
+    pub fn render(&self, placeholders: &Placeholders) -> String {
+        let PuzzleId { year, day } = placeholders.puzzle;
+
+        let answer_type = placeholders
+            .answer_type
+            .as_deref()
+            .or(self.answer_type.as_deref())
+            .unwrap_or(DEFAULT_ANSWER_TYPE);
+
+        let title = placeholders
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+        let [example_1, example_2] = &placeholders.example_answers;
+
+        let (contents, parts) = if placeholders.puzzle.part_count() == 1 {
+            (&self.single_part_contents, ", 1")
+        } else {
+            (&self.contents, "")
+        };
+
+        contents
+            .replace("%YEAR%", &year.to_string())
+            .replace("%PARTS%", parts)
+            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .replace("%DAY_PADDED%", &day.to_string())
+            .replace("%DAY_TITLE%", &title)
+            .replace("%ANSWER_TYPE%", answer_type)
+            .replace(
+                "%EXAMPLE_ANSWER_1%",
+                &format_expected(example_1.as_deref(), answer_type),
+            )
+            .replace(
+                "%EXAMPLE_ANSWER_2%",
+                &format_expected(example_2.as_deref(), answer_type),
+            )
+    }
+}
+
+/// Formats an expected answer as a rust expression of type `Option<answer_type>`.
+fn format_expected(answer: Option<&str>, answer_type: &str) -> String {
+    match answer {
+        Some(answer) if answer_type == "String" => format!("Some(String::from({answer:?}))"),
+        Some(answer) => format!("Some({answer})"),
+        None => "None".into(),
+    }
+}
+
+/// Returns the names of all templates in the template directory, sorted.
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATE_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+
+    names.sort_unstable();
+    names
+}
+
+/// Reads the downloaded description of a puzzle.
+fn read_description(puzzle: PuzzleId) -> Option<String> {
+    fs::read_to_string(format!("data/{}/puzzles/{}.md", puzzle.year, puzzle.day)).ok()
+}
+
+/// Reads the title of a puzzle from its description, e.g. `Day 7: Laboratories`.
+fn parse_title(markdown: &str) -> Option<String> {
+    markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("## --- "))
+        .and_then(|title| title.strip_suffix(" ---"))
+        .map(|title| title.trim().to_string())
+}
+
+/* -------------------------------------------------------------------------- */
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,61 +221,158 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Creates an empty data file, keeping the contents of an existing one.
+/// Returns whether the file was created.
+fn create_data_file(path: &str) -> Result<bool, std::io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(
+    puzzle: PuzzleId,
+    overwrite: bool,
+    template: &str,
+    answer_type: Option<String>,
+) -> Result<(), Error> {
+    let PuzzleId { year, day } = puzzle;
+    let input_path = format!("data/{year}/inputs/{day}.txt");
+    let example_path = format!("data/{year}/examples/{day}.txt");
+    let module_path = format!("src/bin/{puzzle}.rs");
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
+    let template = Template::load(template)
+        .map_err(|e| Error::Failed(format!("failed to load template: {e}")))?;
+
+    for dir in ["inputs", "examples"] {
+        fs::create_dir_all(format!("data/{year}/{dir}"))
+            .map_err(|e| Error::Failed(format!("failed to create data directory: {e}")))?;
+    }
+
+    let mut file = safe_create_file(&module_path, overwrite)
+        .map_err(|e| Error::Failed(format!("failed to create module file: {e}")))?;
+
+    let description = read_description(puzzle);
+    let examples = description
+        .as_deref()
+        .map(Examples::parse)
+        .unwrap_or_default();
+
+    let placeholders = Placeholders {
+        puzzle,
+        title: description.as_deref().and_then(parse_title),
+        answer_type,
+        example_answers: examples.answers(),
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
+    let module = template.render(&placeholders);
+    file.write_all(module.as_bytes())
+        .map_err(|e| Error::Failed(format!("failed to write module contents: {e}")))?;
+    println!("Created module file \"{}\"", &module_path);
+
+    let is_input_created = create_data_file(&input_path)
+        .map_err(|e| Error::Failed(format!("failed to create input file: {e}")))?;
+    if is_input_created {
+        println!("Created empty input file \"{}\"", &input_path);
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    if examples.part_1.is_some() {
+        let paths = examples
+            .write_files(puzzle, false)
+            .map_err(|e| Error::Failed(format!("failed to write example file: {e}")))?;
+        for path in paths {
+            println!("Created example file \"{path}\"");
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    } else {
+        let is_example_created = create_data_file(&example_path)
+            .map_err(|e| Error::Failed(format!("failed to create example file: {e}")))?;
+        if is_example_created {
+            println!("Created empty example file \"{}\"", &example_path);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    println!("---");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Placeholders, Template, parse_title};
+    use crate::{day, template::PuzzleId, year};
+
+    fn placeholders() -> Placeholders {
+        Placeholders {
+            puzzle: PuzzleId::new(year!(2025), day!(7)),
+            title: None,
+            answer_type: None,
+            example_answers: [Some("21".into()), None],
         }
     }
 
-    println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    #[test]
+    fn renders_placeholders() {
+        let template = Template::parse(
+            "solution!(%YEAR%, %DAY_NUMBER%, Day%DAY_PADDED%); // %DAY_TITLE%\n\
+            Option<%ANSWER_TYPE%> %EXAMPLE_ANSWER_1% %EXAMPLE_ANSWER_2%\n",
+        );
+
+        assert_eq!(
+            template.render(&placeholders()),
+            "solution!(2025, 7, Day07); // Day 7\nOption<u64> Some(21) None\n"
+        );
+    }
+
+    #[test]
+    fn reads_answer_type_from_header() {
+        let template =
+            Template::parse("//@ answer_type: String\nOption<%ANSWER_TYPE%> %EXAMPLE_ANSWER_1%\n");
+        assert_eq!(
+            template.render(&placeholders()),
+            "Option<String> Some(String::from(\"21\"))\n"
+        );
+
+        let mut placeholders = placeholders();
+        placeholders.answer_type = Some("i64".into());
+        assert_eq!(template.render(&placeholders), "Option<i64> Some(21)\n");
+    }
+
+    #[test]
+    fn omits_second_part_of_single_part_puzzles() {
+        let template = Template::parse(
+            "solution!(%YEAR%, %DAY_NUMBER%%PARTS%);\n\
+            fn part_one() {}\n\
+            //@ part_two\n\
+            fn part_two() {}\n\
+            //@ end\n\
+            // tests\n",
+        );
+
+        assert_eq!(
+            template.render(&placeholders()),
+            "solution!(2025, 7);\nfn part_one() {}\nfn part_two() {}\n// tests\n"
+        );
+
+        let placeholders = Placeholders {
+            puzzle: PuzzleId::new(year!(2025), day!(12)),
+            ..placeholders()
+        };
+        assert_eq!(
+            template.render(&placeholders),
+            "solution!(2025, 12, 1);\nfn part_one() {}\n// tests\n"
+        );
+    }
+
+    #[test]
+    fn parses_titles() {
+        let markdown = "## --- Day 7: Laboratories ---\n\nSome text.\n\n## --- Part Two ---\n";
+        assert_eq!(
+            parse_title(markdown),
+            Some("Day 7: Laboratories".to_string())
+        );
+        assert_eq!(parse_title("no title"), None);
+    }
 }
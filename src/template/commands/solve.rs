@@ -1,9 +1,18 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::PuzzleId;
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+use super::Error;
+
+/// Runs the binary of a puzzle, passing `bin_args` on to it, see [`RunOptions`](crate::template::runner::RunOptions).
+/// Fails with the exit status of the binary, e.g. when a part panicked.
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    bin_args: &[String],
+) -> Result<(), Error> {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -17,18 +26,21 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
+    cmd_args.extend_from_slice(bin_args);
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
-    }
-
-    let mut cmd = Command::new("cargo")
+    let status = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .status()
+        .map_err(|e| Error::Failed(format!("failed to run cargo: {e}")))?;
 
-    cmd.wait().unwrap();
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::Child {
+            command: "cargo run".into(),
+            status,
+        })
+    }
 }
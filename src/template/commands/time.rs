@@ -1,34 +1,77 @@
 use std::collections::HashSet;
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::answers::Answers;
+pub use crate::template::compare::DEFAULT_THRESHOLD;
+use crate::template::compare::{
+    Comparison, compare_timing, load_baseline, save_baseline as store_baseline,
+};
+use crate::template::history::{ReadmePolicy, RunMeta};
+use crate::template::protocol::PARSE_PART;
+use crate::template::resources::Limits;
+use crate::template::run_multi::{MultiOptions, MultiRun, run_multi};
+use crate::template::stats::format_nanos;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, PuzzleId, Year, all_days, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+use super::{Error, check_days};
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    is_in_process: bool,
+    jobs: usize,
+    allow_parallel_timing: bool,
+    limits: Limits,
+) -> Result<(), Error> {
     let stored_timings = Timings::read_from_file();
 
+    let policy = ReadmePolicy::from_env().map_err(|e| Error::Failed(e.to_string()))?;
+
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                all_days(year)
+                    .filter(|day| !stored_timings.is_day_complete(PuzzleId::new(year, *day)))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = MultiOptions {
+        is_release: !is_in_process,
+        is_timed: true,
+        is_in_process,
+        jobs,
+        allow_parallel_timing,
+        limits,
+    };
+
+    let run = run_multi(year, &days_to_run, &options, &Answers::read_from_file())?;
+    let timings = run_timings(&run)?;
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        // in-process runs use the profile of the runner itself, solution binaries are built for release.
+        let profile = if is_in_process && cfg!(debug_assertions) {
+            "debug"
+        } else {
+            "release"
+        };
+
+        let mut merged_timings = stored_timings.merge(timings);
+        merged_timings.record_history(timings, &RunMeta::capture(profile));
+        merged_timings
+            .store_file()
+            .map_err(|e| Error::Failed(format!("failed to store timings: {e}")))?;
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(&merged_timings.for_readme(policy)) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +80,176 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    check_days(run.days.iter().map(|day| day.status))
+}
+
+/// Returns the timings of a timed run.
+fn run_timings(run: &MultiRun) -> Result<&Timings, Error> {
+    run.timings
+        .as_ref()
+        .ok_or_else(|| Error::Failed("the run did not produce any timings.".into()))
+}
+
+/// Prints every stored run of a puzzle, along with the change of its total time to the previous run.
+pub fn history(puzzle: PuzzleId) -> Result<(), Error> {
+    let timings = Timings::read_from_file();
+    let history = timings.history_of(puzzle);
+
+    if history.is_empty() {
+        println!(
+            "No benchmark history for {puzzle}, store runs with `cargo time {} --store`.",
+            puzzle.day
+        );
+        return Ok(());
+    }
+
+    println!("{ANSI_BOLD}{puzzle}{ANSI_RESET}");
+    println!("-------");
+    println!(
+        "{:<20}  {:<16}  {:<8}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Date", "Commit", "Profile", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    let mut previous: Option<&Timing> = None;
+
+    for entry in history {
+        let timing = &entry.timing;
+
+        let change = previous
+            .filter(|p| p.total_nanos > 0.0)
+            .map(|p| {
+                format!(
+                    " ({:+.1}%)",
+                    (timing.total_nanos / p.total_nanos - 1.0) * 100.0
+                )
+            })
+            .unwrap_or_default();
+
+        println!(
+            "{:<20}  {:<16}  {:<8}  {:>10}  {:>10}  {:>10}  {:>10}{change}",
+            entry.meta.date,
+            entry.meta.revision(),
+            entry.meta.profile,
+            timing.parse.as_deref().unwrap_or("-"),
+            timing.part_str(1).unwrap_or("-"),
+            timing.part_str(2).unwrap_or("-"),
+            format_nanos(timing.total_nanos),
+        );
+
+        previous = Some(timing);
+    }
+
+    Ok(())
+}
+
+/// Benchmarks the selected days and compares each phase against the stored timings or a named baseline.
+/// Fails if any phase got significantly slower by more than `threshold` percent.
+pub fn compare(
+    year: Year,
+    day: Option<Day>,
+    baseline_name: Option<&str>,
+    threshold: f64,
+    is_in_process: bool,
+) -> Result<(), Error> {
+    let baseline = load_baseline(baseline_name).map_err(|e| Error::Failed(e.to_string()))?;
+
+    let days_to_run: HashSet<Day> =
+        day.map_or_else(|| all_days(year).collect(), |day| HashSet::from([day]));
+
+    let options = MultiOptions {
+        is_release: !is_in_process,
+        is_timed: true,
+        is_in_process,
+        jobs: 1,
+        allow_parallel_timing: false,
+        limits: Limits::default(),
+    };
+
+    let run = run_multi(year, &days_to_run, &options, &Answers::read_from_file())?;
+    let timings = run_timings(&run)?;
+
+    println!(
+        "\n{ANSI_BOLD}Comparison{ANSI_RESET} against {}",
+        baseline_name.map_or("the stored timings".to_string(), |name| format!(
+            "baseline `{name}`"
+        ))
+    );
+    println!("-------");
+
+    let mut regressions = 0;
+
+    for timing in &timings.data {
+        let Some(base) = baseline.data.iter().find(|t| t.puzzle() == timing.puzzle()) else {
+            println!("Day {}: no baseline", timing.day);
+            continue;
+        };
+
+        println!("Day {}", timing.day);
+
+        for comparison in compare_timing(base, timing) {
+            print_comparison(&comparison, threshold);
+
+            if comparison.is_regression(threshold) {
+                regressions += 1;
+            }
+        }
+    }
+
+    if regressions > 0 {
+        return Err(Error::Failed(format!(
+            "{regressions} part(s) regressed by more than {threshold}%."
+        )));
+    }
+
+    println!("\nNo part regressed by more than {threshold}%.");
+    check_days(run.days.iter().map(|day| day.status))
+}
+
+fn print_comparison(comparison: &Comparison, threshold: f64) {
+    let phase = if comparison.part == PARSE_PART {
+        "Parse".to_string()
+    } else {
+        format!("Part {}", comparison.part)
+    };
+
+    let verdict = if comparison.is_regression(threshold) {
+        " regressed"
+    } else if comparison.is_improvement(threshold) {
+        " improved"
+    } else if comparison.is_significant() {
+        ""
+    } else {
+        " (not significant)"
+    };
+
+    let p_value = comparison
+        .p_value
+        .map_or("p = -, not enough data".to_string(), |p| {
+            format!("p = {p:.3}")
+        });
+
+    println!(
+        "  {phase}: {} → {} {:+.1}% ({p_value}){verdict}",
+        format_nanos(comparison.baseline_nanos),
+        format_nanos(comparison.current_nanos),
+        comparison.change,
+    );
+}
+
+/// Saves the stored timings as a named baseline for `cargo time --compare --baseline <name>`.
+pub fn save_baseline(name: &str) -> Result<(), Error> {
+    let timings = Timings::read_from_file();
+
+    if timings.data.is_empty() {
+        return Err(Error::Failed(
+            "there are no stored timings, store some with `cargo time --store` first.".into(),
+        ));
+    }
+
+    store_baseline(name, &timings)
+        .map_err(|e| Error::Failed(format!("failed to save baseline: {e}")))?;
+    println!("Saved {} days as baseline `{name}`.", timings.data.len());
+
+    Ok(())
 }
@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Day, Year, all_days,
+    answers::Answers,
+    protocol::{PartRecord, Status},
+    readme_stars,
+    resources::Limits,
+    run_multi::{DayStatus, MultiOptions, run_multi},
+};
+
+use super::{Error, check_days};
+
+/// The result of comparing a part against its known answer.
+enum Verdict {
+    Match,
+    Mismatch { expected: String },
+    Missing,
+}
+
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    accept: bool,
+    is_release: bool,
+    is_in_process: bool,
+    jobs: usize,
+    limits: Limits,
+) -> Result<(), Error> {
+    let days_to_run = day.map_or_else(|| all_days(year).collect(), |day| HashSet::from([day]));
+
+    let options = MultiOptions {
+        is_release,
+        is_timed: false,
+        is_in_process,
+        jobs,
+        allow_parallel_timing: false,
+        limits,
+    };
+
+    let mut answers = Answers::read_from_file();
+    let run = run_multi(year, &days_to_run, &options, &answers)?;
+
+    let mut mismatches = 0;
+    let mut missing = 0;
+    let mut matches = 0;
+    let mut stored = 0;
+    // days with parts that lost their answer, which `--accept` can't replace.
+    let mut lost_answers = HashSet::new();
+
+    println!("\n{ANSI_BOLD}Verify{ANSI_RESET}");
+    println!("------");
+
+    for record in run.days.iter().flat_map(|d| &d.records) {
+        if record.is_parse() {
+            continue;
+        }
+
+        // parts that used to have an answer and no longer return one are regressions, too.
+        // failed and panicked parts are reported by the results of their days.
+        let Some(answer) = record.answer.as_deref() else {
+            if record.status == Status::Unsolved
+                && let Some(expected) = answers.get(record.puzzle(), record.part)
+            {
+                mismatches += 1;
+                lost_answers.insert(record.day);
+                println!(
+                    "Day {} part {}: mismatch (expected {expected}, got nothing)",
+                    record.day, record.part
+                );
+            }
+            continue;
+        };
+
+        match verdict(&answers, record, answer) {
+            Verdict::Match => {
+                matches += 1;
+                println!("Day {} part {}: match", record.day, record.part);
+            }
+            Verdict::Mismatch { expected } => {
+                mismatches += 1;
+                println!(
+                    "Day {} part {}: mismatch (expected {expected}, got {answer})",
+                    record.day, record.part
+                );
+            }
+            Verdict::Missing => {
+                missing += 1;
+                println!(
+                    "Day {} part {}: missing (got {answer})",
+                    record.day, record.part
+                );
+            }
+        }
+
+        if accept {
+            answers.set(record.puzzle(), record.part, answer);
+            stored += 1;
+        }
+    }
+
+    println!("\n{matches} matched, {mismatches} mismatched, {missing} missing.");
+
+    if accept {
+        answers
+            .store_file()
+            .map_err(|e| Error::Failed(format!("failed to store answers: {e}")))?;
+        println!("Stored {stored} answers.");
+
+        if readme_stars::update(&answers).is_err() {
+            eprintln!("Failed to update the stars in the readme.");
+        }
+    }
+
+    // accepted answers replace the known ones, so mismatched days are solved from now on.
+    check_days(run.days.iter().map(|day| match day.status {
+        DayStatus::Mismatched if accept && !lost_answers.contains(&day.day) => DayStatus::Solved,
+        status => status,
+    }))
+}
+
+fn verdict(answers: &Answers, record: &PartRecord, answer: &str) -> Verdict {
+    match answers.get(record.puzzle(), record.part) {
+        Some(expected) if expected == answer => Verdict::Match,
+        Some(expected) => Verdict::Mismatch {
+            expected: expected.into(),
+        },
+        None => Verdict::Missing,
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+    process::{Command, Output},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, PuzzleId,
+    protocol::Status,
+    resources::Limits,
+    run_multi::{child_commands, get_path_for_bin},
+    stats::format_nanos,
+};
+
+use super::Error;
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Changes are acted on once the files were left alone for this long, so that a burst of saves causes a single run.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// The modification time and length of every watched file that exists.
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// Runs until interrupted, failures of a cycle are only reported.
+pub fn handle(puzzle: PuzzleId, is_release: bool) -> Result<(), Error> {
+    println!("{ANSI_ITALIC}Watching {puzzle}, press Ctrl-C to stop.{ANSI_RESET}");
+
+    let mut snapshot = take_snapshot(puzzle);
+    run_cycle(puzzle, is_release);
+
+    let mut last_change: Option<Instant> = None;
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let current = take_snapshot(puzzle);
+        if current != snapshot {
+            snapshot = current;
+            last_change = Some(Instant::now());
+        }
+
+        if last_change.is_some_and(|t| t.elapsed() >= DEBOUNCE) {
+            last_change = None;
+            run_cycle(puzzle, is_release);
+        }
+    }
+}
+
+/// Returns the files that affect a puzzle: its solution, the library, its examples and its input.
+fn watched_files(puzzle: PuzzleId) -> Vec<PathBuf> {
+    let PuzzleId { year, day } = puzzle;
+
+    let mut files = vec![
+        PathBuf::from(get_path_for_bin(puzzle)),
+        PathBuf::from("src/lib.rs"),
+        PathBuf::from(format!("data/{year}/inputs/{day}.txt")),
+    ];
+
+    // examples are either shared, e.g. `07.txt`, or per part, e.g. `07-1.txt`,
+    // and their expected answers are kept in sidecars, e.g. `07.answers`.
+    let day = day.to_string();
+    files.extend(
+        fs::read_dir(format!("data/{year}/examples"))
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| {
+                let is_example = path
+                    .extension()
+                    .is_some_and(|ext| ext == "txt" || ext == "answers");
+                let is_of_day = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .is_some_and(|stem| stem == day || stem.starts_with(&format!("{day}-")));
+
+                is_example && is_of_day
+            }),
+    );
+
+    files
+}
+
+fn take_snapshot(puzzle: PuzzleId) -> Snapshot {
+    watched_files(puzzle)
+        .into_iter()
+        .filter_map(|path| {
+            let metadata = fs::metadata(&path).ok()?;
+            Some((path, (metadata.modified().ok()?, metadata.len())))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Rebuilds the solution, runs its tests and solves the real input.
+fn run_cycle(puzzle: PuzzleId, is_release: bool) {
+    println!("\n{ANSI_BOLD}{puzzle}{ANSI_RESET}");
+    println!("-------");
+
+    let bin_name = puzzle.to_string();
+    let mut build_args = vec!["build", "--quiet", "--bin", &bin_name];
+    let mut test_args = vec!["test", "--bin", &bin_name];
+
+    if is_release {
+        build_args.push("--release");
+        test_args.push("--release");
+    }
+
+    match cargo(&build_args) {
+        Ok(output) if output.status.success() => println!("Build: ok"),
+        Ok(output) => {
+            println!("Build: failed");
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            return;
+        }
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            return;
+        }
+    }
+
+    match cargo(&test_args) {
+        Ok(output) => {
+            let summary = TestSummary::parse(&String::from_utf8_lossy(&output.stdout));
+
+            // the test target failed to compile, so no test ran.
+            if !output.status.success() && summary.is_empty() {
+                println!("Tests: build failed");
+                eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            } else {
+                summary.print();
+            }
+        }
+        Err(e) => eprintln!("Failed to run tests: {e}"),
+    }
+
+    let input_path = format!("data/{}/inputs/{}.txt", puzzle.year, puzzle.day);
+    if !fs::metadata(&input_path).is_ok_and(|m| m.len() > 0) {
+        println!("Solve: skipped, \"{input_path}\" is empty.");
+        return;
+    }
+
+    match child_commands::run_solution(puzzle, false, is_release, &Limits::default(), true) {
+        Ok(output) => {
+            for record in output.records.iter().filter(|r| !r.is_parse()) {
+                match (record.status, &record.answer) {
+                    (Status::Solved, Some(answer)) => println!(
+                        "Part {}: {ANSI_BOLD}{answer}{ANSI_RESET} ({})",
+                        record.part,
+                        format_nanos(record.nanos)
+                    ),
+                    (Status::Failed | Status::Panicked, _) => println!(
+                        "Part {}: ✖ {}",
+                        record.part,
+                        record.error.as_deref().unwrap_or_default()
+                    ),
+                    _ => println!("Part {}: ✖", record.part),
+                }
+            }
+
+            if output.records.is_empty() {
+                println!("Solve: failed");
+                output.print();
+            }
+        }
+        Err(e) => eprintln!("Failed to run solution: {e}"),
+    }
+}
+
+fn cargo(args: &[&str]) -> std::io::Result<Output> {
+    Command::new("cargo").args(args).output()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The outcome of a `cargo test` run, parsed from its output.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct TestSummary {
+    passed: usize,
+    failed: Vec<String>,
+    /// The output of the failed tests, e.g. their assertion messages.
+    failure_output: Vec<String>,
+}
+
+impl TestSummary {
+    fn parse(stdout: &str) -> Self {
+        let mut summary = TestSummary::default();
+        let mut in_failure = false;
+
+        for line in stdout.lines() {
+            if let Some(test) = line.strip_prefix("test ") {
+                if test.ends_with(" ... ok") {
+                    summary.passed += 1;
+                } else if let Some(name) = test.strip_suffix(" ... FAILED") {
+                    summary.failed.push(name.to_string());
+                }
+                continue;
+            }
+
+            if line.starts_with("---- ") {
+                in_failure = true;
+                continue;
+            }
+
+            if line == "failures:" || line.starts_with("test result:") {
+                in_failure = false;
+                continue;
+            }
+
+            if in_failure && !line.is_empty() && !line.starts_with("note: ") {
+                summary.failure_output.push(line.to_string());
+            }
+        }
+
+        summary
+    }
+
+    /// Checks whether no test reported a result.
+    fn is_empty(&self) -> bool {
+        self.passed == 0 && self.failed.is_empty()
+    }
+
+    fn print(&self) {
+        let total = self.passed + self.failed.len();
+
+        if self.failed.is_empty() {
+            println!("Tests: {} passed", self.passed);
+            return;
+        }
+
+        println!("Tests: {} of {total} failed", self.failed.len());
+        for name in &self.failed {
+            println!("  ✖ {name}");
+        }
+        for line in &self.failure_output {
+            println!("    {line}");
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::TestSummary;
+
+    #[test]
+    fn parses_test_output() {
+        let stdout = "\nrunning 3 tests\n\
+            test tests::test_known_answers ... ok\n\
+            test tests::test_part_two ... ok\n\
+            test tests::test_part_one ... FAILED\n\n\
+            failures:\n\n\
+            ---- tests::test_part_one stdout ----\n\n\
+            thread 'tests::test_part_one' panicked at src/bin/2025-07.rs:80:9:\n\
+            assertion `left == right` failed\n  left: Some(20)\n right: Some(21)\n\
+            note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n\n\n\
+            failures:\n    tests::test_part_one\n\n\
+            test result: FAILED. 2 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out\n";
+
+        let summary = TestSummary::parse(stdout);
+
+        assert!(!summary.is_empty());
+        assert_eq!(summary.passed, 2);
+        assert_eq!(summary.failed, vec!["tests::test_part_one".to_string()]);
+        assert_eq!(
+            summary.failure_output,
+            vec![
+                "thread 'tests::test_part_one' panicked at src/bin/2025-07.rs:80:9:",
+                "assertion `left == right` failed",
+                "  left: Some(20)",
+                " right: Some(21)",
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_output_of_failed_builds() {
+        assert!(TestSummary::parse("").is_empty());
+    }
+}
@@ -0,0 +1,241 @@
+/// Compares benchmarks against a baseline, to catch performance regressions before they are merged.
+/// The baseline is either the latest stored timings or a named baseline saved to `data/baselines`.
+use std::{fs, io, path};
+
+use crate::template::{
+    protocol::PARSE_PART,
+    stats::Stats,
+    timings::{Timing, Timings},
+};
+
+static BASELINES_DIR: &str = "./data/baselines";
+
+/// Changes with a p-value below this are considered significant.
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// Changes in percent that are tolerated by default, even if they are significant.
+pub const DEFAULT_THRESHOLD: f64 = 5.0;
+
+/// Loads a named baseline, or the latest stored timings if no name is given.
+pub fn load_baseline(name: Option<&str>) -> Result<Timings, String> {
+    match name {
+        None => Ok(Timings::read_from_file()),
+        Some(name) => baseline_path(name)
+            .and_then(fs::read_to_string)
+            .map_err(|e| format!("could not read baseline `{name}`: {e}"))
+            .and_then(Timings::try_from),
+    }
+}
+
+/// Saves the latest timings of `timings` as a named baseline, without their history.
+pub fn save_baseline(name: &str, timings: &Timings) -> io::Result<()> {
+    let path = baseline_path(name)?;
+    fs::create_dir_all(BASELINES_DIR)?;
+
+    let baseline = Timings {
+        data: timings.data.clone(),
+        history: vec![],
+    };
+    baseline.store_file_at(&path)
+}
+
+/// Returns the path of a named baseline. Names can't contain path separators, so that every
+/// baseline stays inside the baselines directory.
+fn baseline_path(name: &str) -> io::Result<String> {
+    if name.is_empty() || name.contains(path::is_separator) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "baseline names can't be empty or contain path separators",
+        ));
+    }
+
+    Ok(format!("{BASELINES_DIR}/{name}.json"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The change of a single phase of a day relative to the baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    /// The part that was compared, [`PARSE_PART`] for the parse phase.
+    pub part: u8,
+    pub baseline_nanos: f64,
+    pub current_nanos: f64,
+    /// Relative change of the median in percent, positive values are slowdowns.
+    pub change: f64,
+    /// Probability of observing a difference this large if both runs were equally fast,
+    /// [`None`] if either run has too few varying samples to tell, e.g. a single sample.
+    pub p_value: Option<f64>,
+}
+
+impl Comparison {
+    pub fn is_significant(&self) -> bool {
+        self.p_value.is_some_and(|p| p < SIGNIFICANCE_LEVEL)
+    }
+
+    /// Checks whether the phase got significantly slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.is_significant() && self.change > threshold
+    }
+
+    /// Checks whether the phase got significantly faster by more than `threshold` percent.
+    pub fn is_improvement(&self, threshold: f64) -> bool {
+        self.is_significant() && self.change < -threshold
+    }
+}
+
+/// Compares every phase that has statistics in both timings.
+pub fn compare_timing(baseline: &Timing, current: &Timing) -> Vec<Comparison> {
+    let phases = [
+        (PARSE_PART, &baseline.parse_stats, &current.parse_stats),
+        (1, &baseline.part_1_stats, &current.part_1_stats),
+        (2, &baseline.part_2_stats, &current.part_2_stats),
+    ];
+
+    phases
+        .into_iter()
+        .filter_map(|(part, baseline, current)| {
+            let (baseline, current) = (baseline.as_ref()?, current.as_ref()?);
+
+            Some(Comparison {
+                part,
+                baseline_nanos: baseline.median_nanos,
+                current_nanos: current.median_nanos,
+                change: (current.median_nanos / baseline.median_nanos - 1.0) * 100.0,
+                p_value: welch_p_value(baseline, current),
+            })
+        })
+        .collect()
+}
+
+/// Two-sided p-value of Welch's t-test for the means of two sets of samples.
+/// The t distribution is approximated by a normal distribution, which is accurate for the sample
+/// counts the runner collects (at least 10). Only the samples that were not rejected as outliers count,
+/// as the standard deviations are computed from them. Returns [`None`] if the standard error is zero.
+fn welch_p_value(a: &Stats, b: &Stats) -> Option<f64> {
+    #[allow(clippy::cast_precision_loss)]
+    let retained = |stats: &Stats| (stats.samples - stats.outliers) as f64;
+
+    let std_err =
+        (a.std_dev_nanos.powi(2) / retained(a) + b.std_dev_nanos.powi(2) / retained(b)).sqrt();
+
+    // e.g. baselines stored before statistics were collected only have a single sample.
+    if std_err == 0.0 || !std_err.is_finite() {
+        return None;
+    }
+
+    let t = (a.mean_nanos - b.mean_nanos).abs() / std_err;
+    Some(2.0 * (1.0 - normal_cdf(t)))
+}
+
+fn normal_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+/// Approximation of the error function by Abramowitz and Stegun (7.1.26), accurate to 1.5e-7.
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.327_591_1 * x.abs());
+    let polynomial = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let y = 1.0 - polynomial * (-x * x).exp();
+
+    if x < 0.0 { -y } else { y }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{baseline_path, compare_timing, erf, welch_p_value};
+    use crate::{
+        day,
+        template::{stats::Stats, timings::Timing},
+        year,
+    };
+
+    fn stats(nanos: &[u64]) -> Stats {
+        let samples: Vec<Duration> = nanos.iter().map(|x| Duration::from_nanos(*x)).collect();
+        Stats::from_samples(&samples).unwrap()
+    }
+
+    fn timing(part_1: Stats) -> Timing {
+        Timing {
+            part_1_stats: Some(part_1),
+            ..Timing::test(year!(2025), day!(4), Some("-"), None)
+        }
+    }
+
+    #[test]
+    fn rejects_baseline_names_outside_the_baselines_dir() {
+        assert_eq!(baseline_path("main").unwrap(), "./data/baselines/main.json");
+        assert!(baseline_path("../x").is_err());
+        assert!(baseline_path("a/b").is_err());
+        assert!(baseline_path("").is_err());
+    }
+
+    #[test]
+    fn approximates_the_error_function() {
+        assert!(erf(0.0).abs() < 1e-6);
+        assert!((erf(1.0) - 0.842_700_79).abs() < 1e-6);
+        assert!((erf(-1.0) + 0.842_700_79).abs() < 1e-6);
+    }
+
+    #[test]
+    fn detects_significant_changes() {
+        let baseline = stats(&[100, 101, 99, 100, 102, 98, 100, 101, 99, 100]);
+        let same = stats(&[101, 100, 99, 100, 101, 99, 100, 102, 98, 100]);
+        let slower = stats(&[120, 121, 119, 120, 122, 118, 120, 121, 119, 120]);
+
+        assert!(welch_p_value(&baseline, &same).unwrap() > 0.5);
+        assert!(welch_p_value(&baseline, &slower).unwrap() < 0.001);
+    }
+
+    #[test]
+    fn counts_only_retained_samples() {
+        let retained = stats(&[100, 101, 99, 100, 102, 98, 100, 101, 99, 100]);
+        // the same retained samples, along with 90 rejected outliers.
+        let with_outliers = Stats {
+            samples: 100,
+            outliers: 90,
+            ..retained.clone()
+        };
+        let slower = stats(&[101, 102, 100, 101, 103, 99, 101, 102, 100, 101]);
+
+        assert_eq!(
+            welch_p_value(&with_outliers, &slower),
+            welch_p_value(&retained, &slower)
+        );
+    }
+
+    #[test]
+    fn needs_varying_samples_to_tell_significance() {
+        let single = Stats::single(Duration::from_nanos(100));
+        let slower = Stats::single(Duration::from_nanos(200));
+
+        assert_eq!(welch_p_value(&single, &slower), None);
+
+        let comparisons = compare_timing(&timing(single), &timing(slower));
+        assert!(!comparisons[0].is_significant());
+        assert!(!comparisons[0].is_regression(5.0));
+    }
+
+    #[test]
+    fn flags_regressions_past_the_threshold() {
+        let baseline = timing(stats(&[100, 101, 99, 100, 102, 98, 100, 101, 99, 100]));
+        let current = timing(stats(&[120, 121, 119, 120, 122, 118, 120, 121, 119, 120]));
+
+        let comparisons = compare_timing(&baseline, &current);
+        assert_eq!(comparisons.len(), 1);
+
+        let comparison = &comparisons[0];
+        assert_eq!(comparison.part, 1);
+        assert!((comparison.change - 20.0).abs() < 1e-9);
+        assert!(comparison.is_regression(5.0));
+        assert!(!comparison.is_regression(25.0));
+        assert!(!comparison.is_improvement(5.0));
+    }
+}
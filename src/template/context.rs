@@ -0,0 +1,236 @@
+/// The context a solution runs in: whether it solves an example or the real input, and the values of
+/// puzzle parameters that differ between the two, e.g. the number of steps to simulate.
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+/// The kind of input a solution is running on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputKind {
+    /// An example from the puzzle description. This is the default, so that tests calling parts directly
+    /// solve their examples with the example parameters.
+    #[default]
+    Example,
+    /// The personal puzzle input.
+    Real,
+}
+
+/// Passed to the parts of a solution by the runner, read it with [`Context::current`] or through a [`Param`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Context {
+    pub input: InputKind,
+    /// Parameter values set on the command line, e.g. `--param connections=1000`.
+    overrides: HashMap<String, String>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Context> = RefCell::new(Context::default());
+    /// Names of the parameters that were read since the last [`Context::run_solution`].
+    static READ_PARAMS: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+}
+
+impl Context {
+    pub fn new(input: InputKind) -> Self {
+        Self {
+            input,
+            overrides: HashMap::new(),
+        }
+    }
+
+    pub fn example() -> Self {
+        Self::new(InputKind::Example)
+    }
+
+    pub fn real() -> Self {
+        Self::new(InputKind::Real)
+    }
+
+    /// Overrides the value of a parameter, regardless of the kind of input.
+    #[must_use]
+    pub fn with_param(mut self, name: &str, value: &str) -> Self {
+        self.overrides.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn is_example(&self) -> bool {
+        self.input == InputKind::Example
+    }
+
+    /// Returns the context of the solution that is running on the current thread.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Runs `func` with `self` as the current context, restoring the previous context afterwards,
+    /// also if `func` panics.
+    pub fn run<T>(&self, func: impl FnOnce() -> T) -> T {
+        let _restore = Restore(CURRENT.with(|current| current.replace(self.clone())));
+        func()
+    }
+
+    /// Runs a whole solution like [`Context::run`], then warns about overrides that no [`Param`] read,
+    /// as a misspelled `--param` would otherwise silently leave the default in place.
+    pub fn run_solution<T>(&self, func: impl FnOnce() -> T) -> T {
+        READ_PARAMS.with(|read| read.borrow_mut().clear());
+        let result = self.run(func);
+
+        let unread = self.unread_overrides();
+        if !unread.is_empty() {
+            eprintln!(
+                "Warning: no parameter named {} was read, check the names passed to `--param`.",
+                unread.join(", ")
+            );
+        }
+
+        result
+    }
+
+    /// Returns the names of the overrides that no [`Param`] read since the last [`Context::run_solution`], sorted.
+    fn unread_overrides(&self) -> Vec<String> {
+        let mut unread: Vec<String> = READ_PARAMS.with(|read| {
+            let read = read.borrow();
+            self.overrides
+                .keys()
+                .filter(|name| !read.contains(name.as_str()))
+                .map(|name| format!("`{name}`"))
+                .collect()
+        });
+
+        unread.sort_unstable();
+        unread
+    }
+}
+
+/// Makes the context it holds the current one again when dropped.
+struct Restore(Context);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = std::mem::take(&mut self.0);
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Parses a parameter override of the form `name=value`.
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!("invalid parameter `{s}`, expected `name=value`.")),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A named puzzle parameter with separate values for the examples and the real input.
+///
+/// ```
+/// # use advent_of_code::template::context::{Context, Param};
+/// const CONNECTIONS: Param<usize> = Param::new("connections", 10, 1000);
+///
+/// assert_eq!(CONNECTIONS.get(), 10);
+/// assert_eq!(Context::real().run(|| CONNECTIONS.get()), 1000);
+/// assert_eq!(Context::real().with_param("connections", "5").run(|| CONNECTIONS.get()), 5);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Param<T> {
+    pub name: &'static str,
+    pub example: T,
+    pub real: T,
+}
+
+impl<T: Copy + FromStr> Param<T>
+where
+    T::Err: Display,
+{
+    pub const fn new(name: &'static str, example: T, real: T) -> Self {
+        Self {
+            name,
+            example,
+            real,
+        }
+    }
+
+    /// Returns the value of the parameter in the current context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value set on the command line can not be parsed.
+    pub fn get(&self) -> T {
+        CURRENT.with(|current| {
+            let context = current.borrow();
+
+            if !context.overrides.is_empty() {
+                READ_PARAMS.with(|read| read.borrow_mut().insert(self.name));
+            }
+
+            if let Some(value) = context.overrides.get(self.name) {
+                return value.parse().unwrap_or_else(|e| {
+                    panic!("invalid value for parameter `{}`: {e}", self.name)
+                });
+            }
+
+            match context.input {
+                InputKind::Example => self.example,
+                InputKind::Real => self.real,
+            }
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Context, Param, parse_param};
+
+    const STEPS: Param<u32> = Param::new("steps", 6, 64);
+
+    #[test]
+    fn defaults_to_example_values() {
+        assert!(Context::current().is_example());
+        assert_eq!(STEPS.get(), 6);
+    }
+
+    #[test]
+    fn uses_real_values_for_real_input() {
+        assert_eq!(Context::real().run(|| STEPS.get()), 64);
+        assert_eq!(STEPS.get(), 6);
+    }
+
+    #[test]
+    fn restores_context_after_panic() {
+        let res = std::panic::catch_unwind(|| Context::real().run(|| panic!("{}", STEPS.get())));
+
+        assert!(res.is_err());
+        assert_eq!(STEPS.get(), 6);
+    }
+
+    #[test]
+    fn prefers_overrides() {
+        let context = Context::example().with_param("steps", "100");
+        assert_eq!(context.run(|| STEPS.get()), 100);
+    }
+
+    #[test]
+    fn tracks_unread_overrides() {
+        let context = Context::real()
+            .with_param("steps", "100")
+            .with_param("stpes", "100");
+
+        assert_eq!(context.run_solution(|| STEPS.get()), 100);
+        assert_eq!(context.unread_overrides(), vec!["`stpes`".to_string()]);
+    }
+
+    #[test]
+    fn parses_overrides() {
+        assert_eq!(
+            parse_param("connections=1000"),
+            Ok(("connections".into(), "1000".into()))
+        );
+        assert!(parse_param("connections").is_err());
+        assert!(parse_param("=1000").is_err());
+    }
+}
@@ -1,12 +1,14 @@
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+use crate::template::{Year, date::days_from_civil};
+
+pub(crate) const SERVER_UTC_OFFSET: i32 = -5;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
@@ -39,18 +41,37 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a december day of this year's calendar, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        if today.month() == 12 {
+            Self::new(u8::try_from(today.day()).ok()?).filter(|day| year.has_day(*day))
         } else {
             None
         }
     }
 }
 
+impl Day {
+    /// Returns the unix timestamp at which the puzzle of this day unlocks in the given year.
+    pub fn unlocks_at(self, year: Year) -> i64 {
+        let days = days_from_civil(i64::from(year.into_inner()), 12, i64::from(self.0));
+        // puzzles unlock at midnight, server time.
+        days * 86_400 - i64::from(SERVER_UTC_OFFSET) * 3_600
+    }
+
+    /// Returns whether the puzzle of this day is unlocked in the given year.
+    pub fn is_unlocked(self, year: Year) -> bool {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .is_ok_and(|now| {
+                i64::try_from(now.as_secs()).unwrap_or(i64::MAX) >= self.unlocks_at(year)
+            })
+    }
+}
+
 impl Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:02}", self.0)
@@ -94,20 +115,23 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of advent of a year, from the 1st to its final puzzle.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of a year, from the 1st to its final puzzle.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.day_count(),
+        }
     }
 }
 
@@ -115,10 +139,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and a calendar never has more than 25 days.
         let day = Day(self.current);
         self.current += 1;
 
@@ -144,10 +168,11 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{Day, all_days};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2024));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -176,6 +201,22 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_short_calendar() {
+        let days: Vec<Day> = all_days(year!(2025)).collect();
+
+        assert_eq!(days.len(), 12);
+        assert_eq!(days.last(), Some(&Day(12)));
+    }
+
+    #[test]
+    fn unlock_time() {
+        assert_eq!(Day(1).unlocks_at(year!(2025)), 1_764_565_200);
+        assert_eq!(Day(25).unlocks_at(year!(2024)), 1_735_102_800);
+        assert!(Day(1).is_unlocked(year!(2015)));
+        assert!(!Day(1).is_unlocked(year!(9999)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,372 @@
+/// Extracts the example inputs and their expected answers from a puzzle description.
+/// The description is the markdown produced by [`html_to_markdown`](crate::template::aoc_client::html_to_markdown).
+///
+/// The expected answers of an example are kept in a sidecar next to it, e.g. `07.answers` for `07.txt`:
+///
+/// ```text
+/// part_1: 21
+/// part_2: 40
+/// ```
+///
+/// The [`examples!`](crate::examples) macro generates a test for every part listed in a sidecar.
+use std::{fs, io};
+
+use crate::template::{PuzzleId, context::Context, registry::RegisteredSolution};
+
+/// Heading that starts the second part of a puzzle description.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// An example input of a part, along with its expected answer if one was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+/// The examples of both parts of a puzzle.
+/// The second part reuses the example input of the first part, unless its description introduces a new one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub part_1: Option<Example>,
+    pub part_2: Option<Example>,
+}
+
+impl Examples {
+    pub fn parse(markdown: &str) -> Self {
+        let (part_1, part_2) = match markdown.find(PART_TWO_HEADING) {
+            Some(pos) => (&markdown[..pos], Some(&markdown[pos..])),
+            None => (markdown, None),
+        };
+
+        let part_1 = Section::parse(part_1);
+        let part_1_input = part_1.example(true);
+
+        let part_2 = part_2.map(Section::parse).and_then(|section| {
+            let input = section.example(false).or_else(|| part_1_input.clone())?;
+            Some(Example {
+                input,
+                answer: section.answer(),
+            })
+        });
+
+        Self {
+            part_1: part_1_input.map(|input| Example {
+                input,
+                answer: part_1.answer(),
+            }),
+            part_2,
+        }
+    }
+
+    /// Checks whether the parts use different example inputs, which are then stored as `NN-1.txt` and `NN-2.txt`.
+    pub fn is_split(&self) -> bool {
+        match (&self.part_1, &self.part_2) {
+            (Some(a), Some(b)) => a.input != b.input,
+            _ => false,
+        }
+    }
+
+    /// Returns the expected answers of both parts.
+    pub fn answers(&self) -> [Option<String>; 2] {
+        [
+            self.part_1.as_ref().and_then(|e| e.answer.clone()),
+            self.part_2.as_ref().and_then(|e| e.answer.clone()),
+        ]
+    }
+
+    /// Writes the example inputs and the sidecars of their answers to the examples folder of the puzzle,
+    /// returning the paths that were written. Files that already have contents are kept unless `force` is set.
+    pub fn write_files(&self, puzzle: PuzzleId, force: bool) -> io::Result<Vec<String>> {
+        let dir = format!("data/{}/examples", puzzle.year);
+        fs::create_dir_all(&dir)?;
+
+        // (file stem, example input, answers by part)
+        let files: Vec<(String, &Example, [Option<&str>; 2])> = if self.is_split() {
+            [(1, &self.part_1), (2, &self.part_2)]
+                .into_iter()
+                .filter_map(|(part, example)| {
+                    let example = example.as_ref()?;
+                    let answer = example.answer.as_deref();
+                    let answers = if part == 1 {
+                        [answer, None]
+                    } else {
+                        [None, answer]
+                    };
+                    Some((format!("{dir}/{}-{part}", puzzle.day), example, answers))
+                })
+                .collect()
+        } else {
+            let answers = [&self.part_1, &self.part_2]
+                .map(|example| example.as_ref().and_then(|e| e.answer.as_deref()));
+
+            self.part_1
+                .iter()
+                .map(|example| (format!("{dir}/{}", puzzle.day), example, answers))
+                .collect()
+        };
+
+        let mut written = Vec::new();
+
+        for (stem, example, answers) in files {
+            let input_path = format!("{stem}.txt");
+            if force || is_empty(&input_path) {
+                fs::write(&input_path, &example.input)?;
+                written.push(input_path);
+            }
+
+            let sidecar_path = format!("{stem}.answers");
+            if answers.iter().any(Option::is_some) && (force || is_empty(&sidecar_path)) {
+                fs::write(&sidecar_path, format_answers(answers))?;
+                written.push(sidecar_path);
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+fn is_empty(path: &str) -> bool {
+    fs::metadata(path).map_or(true, |m| m.len() == 0)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Formats the expected answers of an example as the contents of its sidecar.
+fn format_answers(answers: [Option<&str>; 2]) -> String {
+    (1..=2)
+        .zip(answers)
+        .filter_map(|(part, answer)| Some(format!("part_{part}: {}\n", answer?)))
+        .collect()
+}
+
+/// Parses the contents of a sidecar into the expected answers of both parts.
+pub fn parse_answers(sidecar: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+
+    for line in sidecar.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+
+        match key.trim() {
+            "part_1" => answers[0] = Some(value.trim().to_string()),
+            "part_2" => answers[1] = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+
+    answers
+}
+
+/// Solves a part of an example and compares the answer with the one in the example's sidecar.
+/// This is called by the tests that [`examples!`](crate::examples) generates.
+pub fn assert_example(solution: &RegisteredSolution, name: &str, part: u8) {
+    let dir = format!("data/{}/examples", solution.puzzle.year);
+
+    let input = fs::read_to_string(format!("{dir}/{name}.txt"))
+        .unwrap_or_else(|e| panic!("could not open example {name}: {e}"));
+    let sidecar = fs::read_to_string(format!("{dir}/{name}.answers"))
+        .unwrap_or_else(|e| panic!("could not open answers of example {name}: {e}"));
+
+    let expected = parse_answers(&sidecar)[usize::from(part - 1)]
+        .clone()
+        .unwrap_or_else(|| panic!("example {name} has no answer for part {part}"));
+
+    let solve = solution
+        .part(part)
+        .unwrap_or_else(|| panic!("{} does not implement part {part}", solution.puzzle));
+
+    assert_eq!(
+        solve(&input, &Context::example()),
+        Ok(Some(expected)),
+        "example {name} part {part} does not match its expected answer"
+    );
+}
+
+/// Generates a test for every example of the current solution that has a sidecar with expected answers,
+/// e.g. `example_07_part_1` for the `part_1` line of `07.answers`. `cargo examples` writes the sidecars.
+/// Use it inside the test module of a solution, adding an example then needs no changes to the solution.
+/// It also generates `test_known_answers`, which checks the real input against its known answers, if both exist.
+///
+/// The tests are generated by `build.rs`, which reruns when the examples of a year change.
+#[macro_export]
+macro_rules! examples {
+    () => {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/examples/",
+            env!("CARGO_BIN_NAME"),
+            ".rs"
+        ));
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A code block of a description, along with the text between it and the previous block.
+struct Block {
+    preceding_text: String,
+    contents: String,
+}
+
+/// The description of a single part.
+struct Section {
+    blocks: Vec<Block>,
+    text: String,
+}
+
+impl Section {
+    fn parse(markdown: &str) -> Self {
+        let mut blocks = Vec::new();
+        let mut text = String::new();
+        let mut preceding_text = String::new();
+        let mut block: Option<String> = None;
+
+        for line in markdown.lines() {
+            match (&mut block, line == "```") {
+                (None, true) => block = Some(String::new()),
+                (Some(contents), true) => {
+                    blocks.push(Block {
+                        preceding_text: std::mem::take(&mut preceding_text),
+                        contents: std::mem::take(contents),
+                    });
+                    block = None;
+                }
+                (Some(contents), false) => {
+                    contents.push_str(line);
+                    contents.push('\n');
+                }
+                (None, false) => {
+                    preceding_text.push_str(line);
+                    preceding_text.push('\n');
+                    text.push_str(line);
+                    text.push('\n');
+                }
+            }
+        }
+
+        Self { blocks, text }
+    }
+
+    /// Returns the first code block that the text introduces as an example.
+    /// With `fallback` set, the first code block is used if no block is introduced as an example.
+    fn example(&self, fallback: bool) -> Option<String> {
+        self.blocks
+            .iter()
+            .find(|b| b.preceding_text.to_ascii_lowercase().contains("example"))
+            .or_else(|| self.blocks.first().filter(|_| fallback))
+            .map(|b| b.contents.clone())
+    }
+
+    /// Returns the last emphasized code value of the text, which is where the puzzles state the example answer.
+    fn answer(&self) -> Option<String> {
+        emphasized_code(&self.text).pop()
+    }
+}
+
+/// Finds the values written as emphasized code, i.e. `` `*value*` `` or ``*`value`*``.
+fn emphasized_code(text: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(['`', '*']) {
+        let (open, close) = if rest[start..].starts_with("`*") {
+            ("`*", "*`")
+        } else if rest[start..].starts_with("*`") {
+            ("*`", "`*")
+        } else {
+            rest = &rest[start + 1..];
+            continue;
+        };
+
+        let value_start = start + open.len();
+        let Some(len) = rest[value_start..].find(close) else {
+            break;
+        };
+
+        let value = rest[value_start..value_start + len].trim();
+        if !value.is_empty() && !value.contains('\n') {
+            values.push(value.to_string());
+        }
+        rest = &rest[value_start + len + close.len()..];
+    }
+
+    values
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, Examples, emphasized_code, format_answers, parse_answers};
+
+    const SHARED: &str = "## --- Day 1: Test ---\n\n\
+        Here is an example list:\n\n```\n1\n2\n3\n```\n\n\
+        Some intermediate state:\n\n```\n6\n```\n\n\
+        In this example, the total is `*6*`.\n\n\
+        ## --- Part Two ---\n\n\
+        Now multiply, which gives *`6`* for the list in `x` and `*`.\n\n\
+        Using the same example, the product is `*6*`. Then the answer is `*42*`.\n";
+
+    const SPLIT: &str = "## --- Day 11: Test ---\n\n\
+        For example:\n\n```\na: b\n```\n\nThere are `*5*` paths.\n\n\
+        ## --- Part Two ---\n\n\
+        Here is a different example:\n\n```\nc: d\n```\n\nThere are `*2*` paths.\n";
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(
+            emphasized_code("a `*1*` b *`2`* c `x` *d* `*`"),
+            vec!["1".to_string(), "2".to_string()]
+        );
+    }
+
+    #[test]
+    fn parses_shared_examples() {
+        let examples = Examples::parse(SHARED);
+
+        assert_eq!(
+            examples.part_1,
+            Some(Example {
+                input: "1\n2\n3\n".into(),
+                answer: Some("6".into())
+            })
+        );
+        assert_eq!(
+            examples.part_2,
+            Some(Example {
+                input: "1\n2\n3\n".into(),
+                answer: Some("42".into())
+            })
+        );
+        assert!(!examples.is_split());
+    }
+
+    #[test]
+    fn parses_split_examples() {
+        let examples = Examples::parse(SPLIT);
+
+        assert!(examples.is_split());
+        assert_eq!(examples.answers(), [Some("5".into()), Some("2".into())]);
+        assert_eq!(examples.part_2.unwrap().input, "c: d\n");
+    }
+
+    #[test]
+    fn parses_first_part_only() {
+        let examples = Examples::parse(SPLIT.split("## --- Part Two").next().unwrap());
+
+        assert!(examples.part_1.is_some());
+        assert_eq!(examples.part_2, None);
+    }
+
+    #[test]
+    fn roundtrips_sidecars() {
+        let sidecar = format_answers([None, Some("40")]);
+        assert_eq!(sidecar, "part_2: 40\n");
+        assert_eq!(parse_answers(&sidecar), [None, Some("40".into())]);
+        assert_eq!(
+            parse_answers("part_1: 21\n\npart_2:  abc \nother: 1\n"),
+            [Some("21".into()), Some("abc".into())]
+        );
+    }
+}
@@ -0,0 +1,241 @@
+/// Benchmark history. Every stored `cargo time` run is appended to `data/timings.json`, together with
+/// metadata that describes the build it was measured on.
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{PuzzleId, date::civil_from_days, timings::Timing};
+
+/// Describes the checkout and build a benchmark was measured on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunMeta {
+    /// UTC date and time of the run, e.g. `2025-12-09T14:03:11Z`.
+    pub date: String,
+    /// Abbreviated hash of the checked out commit, unknown outside of a git repository.
+    pub commit: Option<String>,
+    /// Whether the checkout had uncommitted changes.
+    pub is_dirty: bool,
+    /// The cargo profile the solutions were built with.
+    pub profile: String,
+}
+
+impl RunMeta {
+    /// Captures the metadata of the current checkout.
+    pub fn capture(profile: &str) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self {
+            date: format_timestamp(now),
+            commit: git(&["rev-parse", "--short", "HEAD"]),
+            is_dirty: git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty()),
+            profile: profile.to_string(),
+        }
+    }
+
+    /// Returns the commit the run was measured on, marking dirty checkouts, e.g. `a5ee405-dirty`.
+    pub fn revision(&self) -> String {
+        let commit = self.commit.as_deref().unwrap_or("unknown");
+
+        if self.is_dirty {
+            format!("{commit}-dirty")
+        } else {
+            commit.to_string()
+        }
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Formats a unix timestamp as an ISO 8601 date in UTC.
+fn format_timestamp(secs: u64) -> String {
+    let days = i64::try_from(secs / 86_400).unwrap_or_default();
+    let (year, month, day) = civil_from_days(days);
+    let secs_of_day = secs % 86_400;
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single stored benchmark run of a day.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    pub timing: Timing,
+    pub meta: RunMeta,
+}
+
+impl HistoryEntry {
+    pub fn puzzle(&self) -> PuzzleId {
+        self.timing.puzzle()
+    }
+}
+
+/// Which benchmark of a day is shown in the readme, configured with the `AOC_README_TIMINGS` variable.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadmePolicy {
+    /// The most recent run of each day.
+    #[default]
+    Latest,
+    /// The fastest run of each part across the history.
+    Best,
+}
+
+impl ReadmePolicy {
+    /// Reads the policy from the environment, falling back to [`ReadmePolicy::Latest`].
+    pub fn from_env() -> Result<Self, String> {
+        match env::var("AOC_README_TIMINGS") {
+            Ok(policy) => policy.parse(),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+impl Display for ReadmePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ReadmePolicy::Latest => "latest",
+            ReadmePolicy::Best => "best",
+        })
+    }
+}
+
+impl FromStr for ReadmePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "latest" => Ok(ReadmePolicy::Latest),
+            "best" => Ok(ReadmePolicy::Best),
+            s => Err(format!(
+                "unknown readme timings policy `{s}`, expected `latest` or `best`."
+            )),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map = match JsonValue::from(&value.timing) {
+            JsonValue::Object(map) => map,
+            _ => HashMap::new(),
+        };
+
+        map.insert("date".into(), JsonValue::String(value.meta.date.clone()));
+        map.insert(
+            "commit".into(),
+            value
+                .meta
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("dirty".into(), JsonValue::Boolean(value.meta.is_dirty));
+        map.insert(
+            "profile".into(),
+            JsonValue::String(value.meta.profile.clone()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let timing = Timing::try_from(value)?;
+
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let date = json
+            .get("date")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected history.date to be a string.")?;
+
+        let commit = json.get("commit").and_then(|v| v.get::<String>()).cloned();
+
+        let is_dirty = json
+            .get("dirty")
+            .and_then(|v| v.get::<bool>().copied())
+            .ok_or("Expected history.dirty to be a boolean.")?;
+
+        let profile = json
+            .get("profile")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected history.profile to be a string.")?;
+
+        Ok(HistoryEntry {
+            timing,
+            meta: RunMeta {
+                date: date.clone(),
+                commit,
+                is_dirty,
+                profile: profile.clone(),
+            },
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ReadmePolicy, RunMeta, format_timestamp};
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(1_765_289_000), "2025-12-09T14:03:20Z");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn marks_dirty_revisions() {
+        let meta = RunMeta {
+            date: "2025-12-09T14:03:20Z".into(),
+            commit: Some("a5ee405".into()),
+            is_dirty: true,
+            profile: "release".into(),
+        };
+        assert_eq!(meta.revision(), "a5ee405-dirty");
+
+        let meta = RunMeta {
+            commit: None,
+            is_dirty: false,
+            ..meta
+        };
+        assert_eq!(meta.revision(), "unknown");
+    }
+
+    #[test]
+    fn parses_readme_policies() {
+        assert_eq!("best".parse(), Ok(ReadmePolicy::Best));
+        assert_eq!("latest".parse(), Ok(ReadmePolicy::Latest));
+        assert!("fastest".parse::<ReadmePolicy>().is_err());
+    }
+}
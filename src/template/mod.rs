@@ -1,68 +1,157 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod answers;
+pub mod aoc_client;
 pub mod commands;
+pub mod context;
+pub mod examples;
+pub mod protocol;
+pub mod registry;
+pub mod resources;
 pub mod runner;
+pub mod submissions;
 
 pub use day::*;
+pub use solution::*;
+pub use year::*;
 
+mod compare;
+mod date;
 mod day;
+mod history;
+mod panics;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
+mod solution;
+mod stats;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a puzzle to a string, e.g. `data/2025/inputs/01.txt`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join("data")
+        .join(puzzle.year.to_string())
+        .join(folder)
+        .join(format!("{}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(puzzle.year.to_string())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Pass a type implementing [`Solution`] after the day (e.g. `solution!(2025, 8, Day08)`) to parse the input
+/// once and time the parse phase separately. A fourth parameter (1 or 2) again limits the parts that run.
+///
+/// Besides `main`, the macro generates a `SOLUTION` constant that registers the puzzle with [`crate::solutions`].
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:literal, $day:literal) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]);
+    };
+    ($year:literal, $day:literal, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:literal, $day:literal, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:literal, $day:literal, $solution:ident) => {
+        $crate::solution!(@parsed $year, $day, $solution, [parsed_part_one, 1] [parsed_part_two, 2]);
     };
+    ($year:literal, $day:literal, $solution:ident, 1) => {
+        $crate::solution!(@parsed $year, $day, $solution, [parsed_part_one, 1]);
+    };
+    ($year:literal, $day:literal, $solution:ident, 2) => {
+        $crate::solution!(@parsed $year, $day, $solution, [parsed_part_two, 2]);
+    };
+
+    (@impl $year:literal, $day:literal, $( [$func:expr, $part:expr] )*) => {
+        /// Runs the solution for the current puzzle, returning a record for every phase.
+        pub fn run_solution(
+            input: &str,
+            options: &$crate::template::runner::RunOptions,
+        ) -> Vec<$crate::template::protocol::PartRecord> {
+            use $crate::template::runner::*;
+            options.context.run_solution(|| {
+                let mut records = vec![];
+                $(
+                    if options.runs_part($part) {
+                        records.push(run_part($func, input, PUZZLE, $part, options));
+                    }
+                )*
+                records
+            })
+        }
+
+        $crate::solution!(@common $year, $day, [$( ($part, |input: &str, context: &$crate::template::context::Context| {
+            context.run(|| $crate::template::registry::format_answer(&$func(input)))
+        }) ),*]);
+    };
+
+    (@parsed $year:literal, $day:literal, $solution:ident, $( [$func:ident, $part:expr] )*) => {
+        /// Runs the solution for the current puzzle, returning a record for every phase.
+        pub fn run_solution(
+            input: &str,
+            options: &$crate::template::runner::RunOptions,
+        ) -> Vec<$crate::template::protocol::PartRecord> {
+            use $crate::template::runner::*;
+            options.context.run_solution(|| run_parsed::<$solution>(input, PUZZLE, &[$( $part ),*], options))
+        }
+
+        $crate::solution!(@common $year, $day, [$(
+            ($part, $crate::template::registry::$func::<$solution>)
+        ),*]);
+    };
+
+    (@common $year:literal, $day:literal, [$( $parts:expr ),*]) => {
+        /// The year of the current puzzle.
+        #[allow(dead_code)]
+        const YEAR: $crate::template::Year = $crate::year!($year);
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
+        #[allow(dead_code)]
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId = $crate::template::PuzzleId::new(YEAR, DAY);
+
+        /// Registers the current puzzle with [`advent_of_code::solutions`].
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::RegisteredSolution =
+            $crate::template::registry::RegisteredSolution {
+                puzzle: PUZZLE,
+                run: run_solution,
+                parts: &[$( $parts ),*],
+            };
 
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let options = $crate::template::runner::RunOptions::from_args();
+            let input = options.read_input(PUZZLE);
+            let records = run_solution(&input, &options);
+            $crate::template::runner::exit_if_panicked(&records);
         }
     };
 }
@@ -0,0 +1,116 @@
+/// Catches panics of solution parts, so that the remaining parts still run.
+/// The message and location of a caught panic are captured by a panic hook instead of being printed.
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+/// A panic that was caught while running a part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// The source location of the panic, e.g. `src/bin/2025-05.rs:42:10`.
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static IS_CATCHING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Runs `func`, returning the panic it raised instead of unwinding further.
+pub fn catch<T>(func: impl FnOnce() -> T) -> Result<T, Panic> {
+    // the hook is process-wide, it only captures panics on threads that are inside of `catch`.
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if IS_CATCHING.with(Cell::get) {
+                let panic = Panic {
+                    message: payload_message(info.payload()),
+                    location: info.location().map(ToString::to_string),
+                };
+                CAUGHT.with(|caught| *caught.borrow_mut() = Some(panic));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+
+    let was_catching = IS_CATCHING.with(|is_catching| is_catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    IS_CATCHING.with(|is_catching| is_catching.set(was_catching));
+
+    result.map_err(|payload| {
+        CAUGHT
+            .with(|caught| caught.borrow_mut().take())
+            .unwrap_or_else(|| Panic {
+                message: payload_message(payload.as_ref()),
+                location: None,
+            })
+    })
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::catch;
+
+    #[test]
+    fn returns_results() {
+        assert_eq!(catch(|| 42), Ok(42));
+    }
+
+    #[test]
+    fn captures_message_and_location() {
+        let panic = catch(|| -> u32 { panic!("no solution for {}", "machine 3") }).unwrap_err();
+
+        assert_eq!(panic.message, "no solution for machine 3");
+        assert!(
+            panic
+                .location
+                .as_deref()
+                .is_some_and(|location| location.starts_with("src/template/panics.rs:"))
+        );
+        assert!(
+            panic
+                .to_string()
+                .starts_with("panicked at src/template/panics.rs:")
+        );
+    }
+
+    #[test]
+    fn captures_nested_panics() {
+        let outer = catch(|| {
+            let inner = catch(|| -> u32 { panic!("inner") });
+            assert_eq!(inner.unwrap_err().message, "inner");
+            panic!("outer");
+        });
+        assert_eq!(outer.unwrap_err().message, "outer");
+    }
+}
@@ -0,0 +1,367 @@
+/// Machine-readable results that solution binaries emit when invoked with `--json`.
+/// Every part is written to stdout as a single line holding one JSON object, which lets
+/// `run_multi` consume results without scraping human-readable output.
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, Year, alloc::AllocStats, stats::Stats};
+
+/// The outcome of running a single solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The part returned an error, see [`PartRecord::error`].
+    Failed,
+    /// The part panicked, [`PartRecord::error`] holds the panic message and location.
+    Panicked,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+        })
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            "failed" => Ok(Status::Failed),
+            "panicked" => Ok(Status::Panicked),
+            s => Err(format!("unknown status `{s}`.")),
+        }
+    }
+}
+
+/// Part number used for records describing the parse phase of a [`Solution`](crate::template::Solution).
+pub const PARSE_PART: u8 = 0;
+
+/// A result record for a single solution part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// The error message of a failed part.
+    pub error: Option<String>,
+    /// Median execution time in nanoseconds.
+    pub nanos: f64,
+    pub samples: u128,
+    /// Sample statistics, only present for benched parts.
+    pub stats: Option<Stats>,
+    /// Allocations of a single run, only present for benched parts with the `alloc-stats` feature.
+    pub allocs: Option<AllocStats>,
+}
+
+impl PartRecord {
+    pub fn new(puzzle: PuzzleId, part: u8, answer: Option<String>, stats: Stats) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            part,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            answer,
+            error: None,
+            nanos: stats.median_nanos,
+            samples: stats.samples,
+            stats: (stats.samples > 1).then_some(stats),
+            allocs: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_allocs(self, allocs: Option<AllocStats>) -> Self {
+        Self { allocs, ..self }
+    }
+
+    /// Creates the record of a part that returned an error.
+    pub fn failed(puzzle: PuzzleId, part: u8, error: String, stats: Stats) -> Self {
+        Self {
+            status: Status::Failed,
+            error: Some(error),
+            ..Self::new(puzzle, part, None, stats)
+        }
+    }
+
+    /// Creates the record of a part that panicked, `stats` holds the time until the panic.
+    pub fn panicked(puzzle: PuzzleId, part: u8, panic: String, stats: Stats) -> Self {
+        Self {
+            status: Status::Panicked,
+            error: Some(panic),
+            ..Self::new(puzzle, part, None, stats)
+        }
+    }
+
+    /// Creates the record of a parse phase, which has no answer of its own.
+    pub fn parse(puzzle: PuzzleId, stats: Stats) -> Self {
+        Self {
+            status: Status::Solved,
+            ..Self::new(puzzle, PARSE_PART, None, stats)
+        }
+    }
+
+    pub fn puzzle(&self) -> PuzzleId {
+        PuzzleId::new(self.year, self.day)
+    }
+
+    pub fn is_parse(&self) -> bool {
+        self.part == PARSE_PART
+    }
+
+    /// Serializes the record to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self)
+            .stringify()
+            .expect("part records only contain serializable values")
+    }
+
+    /// Parses a line of output, returning [`None`] if it is not a result record.
+    pub fn from_line(line: &str) -> Option<Self> {
+        let line = line.trim();
+
+        if !line.starts_with('{') {
+            return None;
+        }
+
+        JsonValue::from_str(line)
+            .ok()
+            .and_then(|json| Self::try_from(&json).ok())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "error".into(),
+            value
+                .error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "allocs".into(),
+            value
+                .allocs
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartRecord {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected record.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected record.part to be a number.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let error = json.get("error").and_then(|v| v.get::<String>()).cloned();
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|samples| *samples as u128)
+            .ok_or("Expected record.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(Stats::try_from(v)?),
+            _ => None,
+        };
+
+        let allocs = match json.get("allocs") {
+            Some(v) if !v.is_null() => Some(AllocStats::try_from(v)?),
+            _ => None,
+        };
+
+        Ok(PartRecord {
+            year,
+            day,
+            part,
+            status,
+            answer: answer.cloned(),
+            error,
+            nanos,
+            samples,
+            stats,
+            allocs,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PartRecord, Status};
+    use crate::{
+        day,
+        template::{PuzzleId, alloc::AllocStats, stats::Stats},
+        year,
+    };
+
+    const PUZZLE: PuzzleId = PuzzleId::new(year!(2025), day!(3));
+
+    #[test]
+    fn roundtrips_records() {
+        let stats = Stats::from_samples(&[
+            Duration::from_nanos(10),
+            Duration::from_nanos(12),
+            Duration::from_nanos(11),
+        ])
+        .unwrap();
+
+        let record = PartRecord::new(PUZZLE, 2, Some("42 samples)\nfoo".into()), stats);
+        let parsed = PartRecord::from_line(&record.to_json_line()).unwrap();
+
+        assert_eq!(parsed, record);
+        assert_eq!(parsed.status, Status::Solved);
+        assert_eq!(parsed.nanos, 11_f64);
+        assert_eq!(parsed.samples, 3);
+    }
+
+    #[test]
+    fn omits_statistics_for_single_runs() {
+        let record = PartRecord::new(PUZZLE, 1, None, Stats::single(Duration::from_nanos(7)));
+        assert_eq!(record.status, Status::Unsolved);
+        assert_eq!(record.stats, None);
+        assert_eq!(record.nanos, 7_f64);
+    }
+
+    #[test]
+    fn roundtrips_failed_records() {
+        let record = PartRecord::failed(
+            PUZZLE,
+            2,
+            "no solution for machine 3".into(),
+            Stats::single(Duration::from_nanos(7)),
+        );
+        let parsed = PartRecord::from_line(&record.to_json_line()).unwrap();
+
+        assert_eq!(parsed, record);
+        assert_eq!(parsed.status, Status::Failed);
+        assert_eq!(parsed.answer, None);
+        assert_eq!(parsed.error.as_deref(), Some("no solution for machine 3"));
+    }
+
+    #[test]
+    fn roundtrips_panicked_records() {
+        let record = PartRecord::panicked(
+            PUZZLE,
+            1,
+            "panicked at src/bin/2025-03.rs:12:5: index out of bounds".into(),
+            Stats::single(Duration::from_nanos(7)),
+        );
+        let parsed = PartRecord::from_line(&record.to_json_line()).unwrap();
+
+        assert_eq!(parsed, record);
+        assert_eq!(parsed.status, Status::Panicked);
+    }
+
+    #[test]
+    fn roundtrips_allocations() {
+        let allocs = AllocStats {
+            allocations: 3,
+            bytes: 2048,
+            peak_bytes: 1024,
+        };
+        let record = PartRecord::new(PUZZLE, 1, Some("7".into()), Stats::single(Duration::ZERO))
+            .with_allocs(Some(allocs));
+        let parsed = PartRecord::from_line(&record.to_json_line()).unwrap();
+
+        assert_eq!(parsed.allocs, Some(allocs));
+    }
+
+    #[test]
+    fn ignores_other_output() {
+        assert_eq!(
+            PartRecord::from_line("Part 1: 0 (74.13ns @ 100000 samples)"),
+            None
+        );
+        assert_eq!(PartRecord::from_line("{ \"foo\": 1 }"), None);
+        assert_eq!(PartRecord::from_line("{ not json"), None);
+    }
+}
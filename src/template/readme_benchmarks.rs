@@ -2,7 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
+use crate::template::PuzzleId;
+use crate::template::alloc::format_bytes;
 use crate::template::timings::Timings;
 
 static MARKER: &str = "<!--- benchmarking table --->";
@@ -21,17 +22,18 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub(crate) pos_start: usize,
+    pub(crate) pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates a table delimited by two occurrences of `marker`.
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,78 +54,113 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(prefix: &str, timings: &Timings) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    // the most recent year comes first.
+    for year in timings.years().into_iter().rev() {
+        let timings = timings.for_year(year);
+
+        lines.push(String::new());
+        lines.push(format!("{prefix}# {year}"));
+        lines.push(String::new());
+
+        // allocation columns are only shown if the timings were taken with the `alloc-stats` feature.
+        let has_allocs = timings.data.iter().any(|t| !t.allocs.is_empty());
+
+        if has_allocs {
+            lines.push("| Day | Parse | Part 1 | Part 2 | Allocations | Peak memory |".into());
+            lines.push("| :---: | :---: | :---: | :---: | :---: | :---:  |".into());
+        } else {
+            lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+            lines.push("| :---: | :---: | :---: | :---:  |".into());
+        }
+
+        for timing in &timings.data {
+            let path = get_path_for_bin(timing.puzzle());
+            let mut line = format!(
+                "| [Day {}]({}) | `{}` | `{}` | `{}` |",
+                timing.day.into_inner(),
+                path,
+                timing.parse.as_deref().unwrap_or("-"),
+                timing.part_str(1).unwrap_or("-"),
+                timing.part_str(2).unwrap_or("-")
+            );
+
+            if has_allocs {
+                match timing.total_allocs() {
+                    Some(allocs) => line.push_str(&format!(
+                        " `{} ({})` | `{}` |",
+                        allocs.allocations,
+                        format_bytes(allocs.bytes),
+                        format_bytes(allocs.peak_bytes)
+                    )),
+                    None => line.push_str(" `-` | `-` |"),
+                }
+            }
+
+            lines.push(line);
+        }
+
+        lines.push(String::new());
+        lines.push(format!("**Total: {:.2}ms**", timings.total_millis()));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(s: &mut String, timings: &Timings) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", timings);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(timings: &Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
+    use std::collections::BTreeMap;
+
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::{alloc::AllocStats, timings::Timing, timings::Timings},
+        year,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    total_nanos: 3e+7,
+                    ..Timing::test(year!(2025), day!(1), Some("10ms"), Some("20ms"))
+                },
+                Timing {
+                    parse: Some("5ms".into()),
+                    total_nanos: 7e+7,
+                    ..Timing::test(year!(2025), day!(2), Some("30ms"), Some("40ms"))
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    total_nanos: 9e+7,
+                    ..Timing::test(year!(2025), day!(4), Some("40ms"), Some("50ms"))
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    total_nanos: 1e+6,
+                    ..Timing::test(year!(2024), day!(25), Some("1ms"), None)
                 },
             ],
+            history: vec![],
         }
     }
 
@@ -131,28 +168,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings()).unwrap();
+        update_content(&mut s, &get_mock_timings()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,24 +197,70 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings()).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "### 2025",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2025-01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2025-02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2025-04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
+            "",
+            "### 2024",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 25](./src/bin/2024-25.rs) | `-` | `1ms` | `-` |",
+            "",
+            "**Total: 1.00ms**",
             "<!--- benchmarking table --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn shows_allocations_if_counted() {
+        let mut timings = get_mock_timings();
+        timings.data[1].allocs = BTreeMap::from([
+            (
+                0,
+                AllocStats {
+                    allocations: 2,
+                    bytes: 1024,
+                    peak_bytes: 1024,
+                },
+            ),
+            (
+                1,
+                AllocStats {
+                    allocations: 3,
+                    bytes: 2048,
+                    peak_bytes: 1536,
+                },
+            ),
+        ]);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, &timings).unwrap();
+
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 | Allocations | Peak memory |"));
+        assert!(
+            s.contains("| [Day 1](./src/bin/2025-01.rs) | `-` | `10ms` | `20ms` | `-` | `-` |")
+        );
+        assert!(s.contains(
+            "| [Day 2](./src/bin/2025-02.rs) | `5ms` | `30ms` | `40ms` | `5 (3.0 KiB)` | `1.5 KiB` |"
+        ));
+        // years without counted allocations keep the plain table.
+        assert!(s.contains("| [Day 25](./src/bin/2024-25.rs) | `-` | `1ms` | `-` |\n"));
+    }
 }
@@ -0,0 +1,196 @@
+/// Module that updates the readme with a table of stars for every year, based on the known answers.
+/// The `aoc-readme-stars` workflow owns its own table and only covers `AOC_YEAR`, so this table has a marker of its own.
+/// Stars that the table already shows are kept, as the known answers may not cover every solved puzzle.
+use std::{collections::BTreeMap, fs};
+
+use crate::template::answers::Answers;
+use crate::template::readme_benchmarks::{Error, locate_table};
+use crate::template::{PuzzleId, Year};
+
+static MARKER: &str = "<!--- stars by year table --->";
+
+/// The stars of every puzzle, as `(part 1, part 2)`.
+type Stars = BTreeMap<PuzzleId, (bool, bool)>;
+
+fn star(is_earned: bool) -> &'static str {
+    if is_earned { "⭐" } else { " " }
+}
+
+/// Parses the stars of a row of a previously written table.
+fn parse_row(line: &str) -> Option<(PuzzleId, (bool, bool))> {
+    let (_, rest) = line.split_once("](https://adventofcode.com/")?;
+    let (path, cells) = rest.split_once(')')?;
+    let (year, day) = path.split_once("/day/")?;
+    let puzzle = PuzzleId::new(year.parse().ok()?, day.parse().ok()?);
+
+    let mut cells = cells.split('|').skip(1).map(|cell| cell.contains('⭐'));
+    Some((puzzle, (cells.next()?, cells.next()?)))
+}
+
+/// Adds the stars of the known answers to the stars of the existing table.
+fn merge_stars(table: &str, answers: &Answers) -> Stars {
+    let mut stars: Stars = table.lines().filter_map(parse_row).collect();
+
+    for answer in answers.data.iter().filter(|a| a.year.has_day(a.day)) {
+        let (part_1, part_2) = stars.entry(answer.puzzle()).or_default();
+        *part_1 |= answer.part_1.is_some();
+        *part_2 |= answer.part_2.is_some();
+    }
+
+    stars
+}
+
+/// Checks whether every part of a year's puzzles has a star, apart from the final puzzle's second part.
+/// That part has no puzzle of its own, its star is awarded once all other stars of the year are earned.
+fn is_year_complete(stars: &Stars, year: Year) -> bool {
+    year.days().all(|day| {
+        stars
+            .get(&PuzzleId::new(year, day))
+            .is_some_and(|(part_1, part_2)| *part_1 && (*part_2 || year.part_count(day) == 1))
+    })
+}
+
+fn construct_table(prefix: &str, stars: &Stars) -> String {
+    let mut years: Vec<Year> = stars.keys().map(|puzzle| puzzle.year).collect();
+    years.dedup();
+
+    let mut lines: Vec<String> = vec![MARKER.into(), format!("{prefix} Stars")];
+
+    // the most recent year comes first.
+    for year in years.into_iter().rev() {
+        let is_complete = is_year_complete(stars, year);
+
+        lines.push(String::new());
+        lines.push(format!("{prefix}# {year}"));
+        lines.push(String::new());
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: |".into());
+
+        for (puzzle, (part_1, part_2)) in stars.iter().filter(|(p, _)| p.year == year) {
+            let day = puzzle.day.into_inner();
+            let part_2 = *part_2 || (is_complete && puzzle.part_count() == 1);
+            lines.push(format!(
+                "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} |",
+                star(*part_1),
+                star(part_2)
+            ));
+        }
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, answers: &Answers) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let stars = merge_stars(&s[positions.pos_start..positions.pos_end], answers);
+    let table = construct_table("##", &stars);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+pub fn update(answers: &Answers) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, answers)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MARKER, update_content};
+    use crate::{
+        day,
+        template::{PuzzleId, answers::Answers},
+        year,
+    };
+
+    #[test]
+    fn format_stars() {
+        let mut answers = Answers::default();
+        answers.set(PuzzleId::new(year!(2025), day!(1)), 1, "3");
+        answers.set(PuzzleId::new(year!(2025), day!(1)), 2, "6");
+        answers.set(PuzzleId::new(year!(2025), day!(2)), 1, "12");
+        answers.set(PuzzleId::new(year!(2024), day!(25)), 1, "42");
+
+        let mut s = format!("foo\n{}\n{}\nbar", MARKER, MARKER);
+        update_content(&mut s, &answers).unwrap();
+
+        let expected = [
+            "foo",
+            "<!--- stars by year table --->",
+            "## Stars",
+            "",
+            "### 2025",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ |   |",
+            "",
+            "### 2024",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 25](https://adventofcode.com/2024/day/25) | ⭐ |   |",
+            "<!--- stars by year table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn awards_final_star_for_complete_year() {
+        let mut answers = Answers::default();
+        for day in year!(2025).days() {
+            let puzzle = PuzzleId::new(year!(2025), day);
+            answers.set(puzzle, 1, "1");
+            if puzzle.part_count() == 2 {
+                answers.set(puzzle, 2, "2");
+            }
+        }
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, &answers).unwrap();
+        assert!(s.contains("| [Day 12](https://adventofcode.com/2025/day/12) | ⭐ | ⭐ |"));
+
+        answers.data.retain(|a| a.day != day!(11));
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, &answers).unwrap();
+        assert!(s.contains("| [Day 12](https://adventofcode.com/2025/day/12) | ⭐ |   |"));
+    }
+
+    #[test]
+    fn keeps_existing_stars() {
+        let mut s = [
+            MARKER,
+            "## Stars",
+            "",
+            "### 2025",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ |   |",
+            MARKER,
+        ]
+        .join("\n");
+
+        let mut answers = Answers::default();
+        answers.set(PuzzleId::new(year!(2025), day!(2)), 2, "12");
+        answers.set(PuzzleId::new(year!(2025), day!(3)), 1, "7");
+
+        update_content(&mut s, &answers).unwrap();
+        assert!(s.contains("| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |"));
+        assert!(s.contains("| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ | ⭐ |"));
+        assert!(s.contains("| [Day 3](https://adventofcode.com/2025/day/3) | ⭐ |   |"));
+
+        // rewriting the table with the same answers leaves it unchanged.
+        let written = s.clone();
+        update_content(&mut s, &answers).unwrap();
+        assert_eq!(s, written);
+    }
+}
@@ -0,0 +1,45 @@
+/// Types that describe the solutions compiled into the library, see [`crate::solutions`].
+use crate::template::{
+    PartOutput, PuzzleId, Solution, context::Context, protocol::PartRecord, runner::RunOptions,
+};
+
+/// A solution part that takes the raw puzzle input and returns its formatted answer, see [`PartOutput`].
+/// The part runs in the given context, so that its parameters match the kind of input.
+pub type PartFn = fn(&str, &Context) -> Result<Option<String>, String>;
+
+/// A puzzle's solution as registered by the `solution!` macro.
+pub struct RegisteredSolution {
+    pub puzzle: PuzzleId,
+    /// Runs the solution the same way its binary does, returning a record for every phase.
+    pub run: fn(&str, &RunOptions) -> Vec<PartRecord>,
+    /// The parts implemented by the solution.
+    pub parts: &'static [(u8, PartFn)],
+}
+
+impl RegisteredSolution {
+    /// Returns the function that solves the given part, if the solution implements it.
+    pub fn part(&self, part: u8) -> Option<PartFn> {
+        self.parts.iter().find(|(p, _)| *p == part).map(|(_, f)| *f)
+    }
+}
+
+/// Formats the answer of a solution part, or the message of its error.
+pub fn format_answer(answer: &impl PartOutput) -> Result<Option<String>, String> {
+    answer.to_answer()
+}
+
+/// Parses the input and solves the first part of a [`Solution`].
+pub fn parsed_part_one<S: Solution>(
+    input: &str,
+    context: &Context,
+) -> Result<Option<String>, String> {
+    context.run(|| format_answer(&S::part_one(&S::parse(input))))
+}
+
+/// Parses the input and solves the second part of a [`Solution`].
+pub fn parsed_part_two<S: Solution>(
+    input: &str,
+    context: &Context,
+) -> Result<Option<String>, String> {
+    context.run(|| format_answer(&S::part_two(&S::parse(input))))
+}
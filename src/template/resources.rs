@@ -0,0 +1,502 @@
+/// Resources used by solution binaries, and limits on them. The usage of a child process is collected
+/// when it is reaped, using `wait4` on unix systems. Other systems neither report usage nor enforce limits.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io,
+    process::{Child, Command, ExitStatus},
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    alloc::format_bytes,
+    stats::{format_nanos, parse_duration},
+};
+
+/// The resources a child process used over its lifetime.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResourceUsage {
+    /// Maximum resident set size in bytes.
+    pub max_rss_bytes: u64,
+    /// CPU time spent in user mode.
+    pub user: Duration,
+    /// CPU time spent in kernel mode.
+    pub system: Duration,
+    /// Time from spawning the process until it exited.
+    pub wall: Duration,
+}
+
+impl Display for ResourceUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nanos = |d: Duration| format_nanos(d.as_secs_f64() * 1e9);
+
+        write!(
+            f,
+            "wall {} · user {} · sys {} · max RSS {}",
+            nanos(self.wall),
+            nanos(self.user),
+            nanos(self.system),
+            format_bytes(self.max_rss_bytes)
+        )
+    }
+}
+
+/// Limits that are enforced on a child process. They are only enforced on unix systems.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock time after which the process is killed.
+    pub timeout: Option<Duration>,
+    /// Maximum size of the address space of the process in bytes, enforced with `RLIMIT_AS`.
+    /// Allocations beyond it fail, which aborts the process.
+    pub memory_bytes: Option<u64>,
+}
+
+impl Limits {
+    /// Parses the limits passed on the command line, e.g. `--timeout 30s --memory-limit 2GiB`.
+    pub fn parse(timeout: Option<&str>, memory: Option<&str>) -> Result<Self, String> {
+        Ok(Self {
+            timeout: timeout.map(parse_duration).transpose()?,
+            memory_bytes: memory.map(parse_bytes).transpose()?,
+        })
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        self.timeout.is_none() && self.memory_bytes.is_none()
+    }
+
+    /// Applies the memory limit to the processes spawned by `command`.
+    #[cfg(unix)]
+    fn apply(&self, command: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        let Some(bytes) = self.memory_bytes else {
+            return;
+        };
+
+        let limit = libc::rlimit {
+            rlim_cur: bytes as libc::rlim_t,
+            rlim_max: bytes as libc::rlim_t,
+        };
+
+        // SAFETY: the closure runs between fork and exec, it only calls `setrlimit`, which is
+        // async-signal-safe, and does not allocate.
+        unsafe {
+            command.pre_exec(move || {
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                    Ok(())
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            });
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn apply(&self, _command: &mut Command) {}
+}
+
+/// Parses a number of bytes with an optional binary unit, e.g. `512MiB` or `2G`.
+pub fn parse_bytes(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let unit_start = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(unit_start);
+
+    let value: f64 = value.parse().map_err(|_| format!("invalid size `{s}`."))?;
+
+    let factor: u64 = match unit.trim() {
+        "" | "B" => 1,
+        "K" | "KB" | "KiB" => 1 << 10,
+        "M" | "MB" | "MiB" => 1 << 20,
+        "G" | "GB" | "GiB" => 1 << 30,
+        unit => return Err(format!("unknown unit `{unit}` in size `{s}`.")),
+    };
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    Ok((value * factor as f64) as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// How a child process ended.
+#[derive(Debug)]
+pub struct Exit {
+    pub status: ExitStatus,
+    /// The resources the process used, only available on unix systems.
+    pub usage: Option<ResourceUsage>,
+    /// Whether the process was killed because it exceeded its timeout.
+    pub is_timed_out: bool,
+}
+
+impl Exit {
+    /// Whether the process was killed with `SIGKILL` by something other than its watchdog while its
+    /// peak resident set size was close to `limit`, as happens when the kernel runs out of memory for it.
+    #[cfg(unix)]
+    pub fn is_killed_near(&self, limit: u64) -> bool {
+        use std::os::unix::process::ExitStatusExt;
+
+        /// Share of the limit the peak resident set size has to reach to count as near it.
+        const NEAR_LIMIT: f64 = 0.9;
+
+        #[allow(clippy::cast_precision_loss)]
+        let is_near_limit = self
+            .usage
+            .is_some_and(|usage| usage.max_rss_bytes as f64 >= limit as f64 * NEAR_LIMIT);
+
+        !self.is_timed_out && self.status.signal() == Some(libc::SIGKILL) && is_near_limit
+    }
+
+    #[cfg(not(unix))]
+    pub fn is_killed_near(&self, _limit: u64) -> bool {
+        false
+    }
+}
+
+/// A child process that is spawned with [`Limits`].
+pub struct LimitedChild {
+    pub child: Child,
+    started: Instant,
+    watchdog: Option<Watchdog>,
+}
+
+impl LimitedChild {
+    pub fn spawn(command: &mut Command, limits: &Limits) -> io::Result<Self> {
+        limits.apply(command);
+
+        let started = Instant::now();
+        let child = command.spawn()?;
+        let watchdog = limits
+            .timeout
+            .map(|timeout| Watchdog::start(&child, timeout))
+            .transpose()?;
+
+        Ok(Self {
+            child,
+            started,
+            watchdog,
+        })
+    }
+
+    /// Waits for the process to exit, collecting the resources it used with `wait4`.
+    #[cfg(unix)]
+    pub fn wait(self) -> io::Result<Exit> {
+        use std::{mem, os::unix::process::ExitStatusExt};
+
+        let pid = pid_of(&self.child)?;
+
+        // wait for the process to exit without reaping it, so that the watchdog can not kill a recycled pid.
+        retry_interrupted(|| {
+            // SAFETY: `siginfo_t` is a plain C struct, for which all zeroes is a valid value.
+            let mut info: libc::siginfo_t = unsafe { mem::zeroed() };
+            // SAFETY: `info` is valid for writes.
+            unsafe {
+                libc::waitid(
+                    libc::P_PID,
+                    pid.unsigned_abs(),
+                    &mut info,
+                    libc::WEXITED | libc::WNOWAIT,
+                )
+            }
+        })?;
+
+        let is_timed_out = self.watchdog.is_some_and(Watchdog::stop);
+
+        let mut status = 0;
+        // SAFETY: `rusage` is a plain C struct, for which all zeroes is a valid value.
+        let mut usage: libc::rusage = unsafe { mem::zeroed() };
+
+        // SAFETY: both pointers are valid for writes. The child has not been reaped yet, as `Child::wait`
+        // is never called on it.
+        retry_interrupted(|| unsafe { libc::wait4(pid, &mut status, 0, &mut usage) })?;
+
+        let wall = self.started.elapsed();
+
+        // linux reports the maximum resident set size in KiB, macOS in bytes.
+        #[allow(clippy::cast_sign_loss)]
+        let max_rss = usage.ru_maxrss.max(0) as u64;
+        let max_rss_bytes = if cfg!(target_os = "macos") {
+            max_rss
+        } else {
+            max_rss * 1024
+        };
+
+        Ok(Exit {
+            status: ExitStatus::from_raw(status),
+            usage: Some(ResourceUsage {
+                max_rss_bytes,
+                user: duration_from_timeval(usage.ru_utime),
+                system: duration_from_timeval(usage.ru_stime),
+                wall,
+            }),
+            is_timed_out,
+        })
+    }
+
+    /// Waits for the process to exit, resource usage is not available on this system.
+    #[cfg(not(unix))]
+    pub fn wait(mut self) -> io::Result<Exit> {
+        Ok(Exit {
+            status: self.child.wait()?,
+            usage: None,
+            is_timed_out: false,
+        })
+    }
+}
+
+/// Kills a process once its timeout elapsed, unless it exited before.
+struct Watchdog {
+    has_exited: Arc<Mutex<bool>>,
+    cancel: mpsc::Sender<()>,
+    thread: JoinHandle<bool>,
+}
+
+impl Watchdog {
+    #[cfg(unix)]
+    fn start(child: &Child, timeout: Duration) -> io::Result<Self> {
+        let pid = pid_of(child)?;
+        let has_exited = Arc::new(Mutex::new(false));
+        let (cancel, cancelled) = mpsc::channel();
+
+        let thread = thread::spawn({
+            let has_exited = Arc::clone(&has_exited);
+
+            move || {
+                if cancelled.recv_timeout(timeout) != Err(RecvTimeoutError::Timeout) {
+                    return false;
+                }
+
+                let has_exited = has_exited.lock().unwrap();
+                if *has_exited {
+                    return false;
+                }
+
+                // SAFETY: the process is not reaped before `has_exited` is set, so `pid` still refers to it.
+                unsafe { libc::kill(pid, libc::SIGKILL) };
+                true
+            }
+        });
+
+        Ok(Self {
+            has_exited,
+            cancel,
+            thread,
+        })
+    }
+
+    /// Processes can not be killed by pid on this system, the timeout is not enforced.
+    #[cfg(not(unix))]
+    fn start(_child: &Child, _timeout: Duration) -> io::Result<Self> {
+        let (cancel, _) = mpsc::channel();
+
+        Ok(Self {
+            has_exited: Arc::new(Mutex::new(false)),
+            cancel,
+            thread: thread::spawn(|| false),
+        })
+    }
+
+    /// Stops the watchdog after the process exited, returning whether it killed the process.
+    fn stop(self) -> bool {
+        *self.has_exited.lock().unwrap() = true;
+        drop(self.cancel);
+        self.thread.join().unwrap_or(false)
+    }
+}
+
+#[cfg(unix)]
+fn pid_of(child: &Child) -> io::Result<libc::pid_t> {
+    libc::pid_t::try_from(child.id()).map_err(io::Error::other)
+}
+
+/// Calls `func` until it is not interrupted by a signal, returning the error it failed with otherwise.
+#[cfg(unix)]
+fn retry_interrupted(mut func: impl FnMut() -> libc::c_int) -> io::Result<libc::c_int> {
+    loop {
+        let result = func();
+
+        if result != -1 {
+            return Ok(result);
+        }
+
+        let error = io::Error::last_os_error();
+        if error.kind() != io::ErrorKind::Interrupted {
+            return Err(error);
+        }
+    }
+}
+
+#[cfg(unix)]
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+fn duration_from_timeval(time: libc::timeval) -> Duration {
+    Duration::new(
+        time.tv_sec.max(0) as u64,
+        (time.tv_usec.max(0) * 1000) as u32,
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&ResourceUsage> for JsonValue {
+    fn from(value: &ResourceUsage) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            map.insert(
+                "max_rss_bytes".into(),
+                JsonValue::Number(value.max_rss_bytes as f64),
+            );
+            map.insert(
+                "user_nanos".into(),
+                JsonValue::Number(value.user.as_nanos() as f64),
+            );
+            map.insert(
+                "system_nanos".into(),
+                JsonValue::Number(value.system.as_nanos() as f64),
+            );
+            map.insert(
+                "wall_nanos".into(),
+                JsonValue::Number(value.wall.as_nanos() as f64),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for ResourceUsage {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected resource usage to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected usage.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = |key: &str| number(key).map(|nanos| Duration::from_nanos(nanos as u64));
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(ResourceUsage {
+            max_rss_bytes: number("max_rss_bytes")? as u64,
+            user: duration("user_nanos")?,
+            system: duration("system_nanos")?,
+            wall: duration("wall_nanos")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use tinyjson::JsonValue;
+
+    use std::process::Command;
+
+    use super::{LimitedChild, Limits, ResourceUsage, parse_bytes};
+
+    #[test]
+    fn roundtrips_usage() {
+        let usage = ResourceUsage {
+            max_rss_bytes: 12 * 1024 * 1024,
+            user: Duration::from_millis(800),
+            system: Duration::from_millis(20),
+            wall: Duration::from_millis(900),
+        };
+        let json = JsonValue::from(&usage);
+        assert_eq!(ResourceUsage::try_from(&json), Ok(usage));
+    }
+
+    #[test]
+    fn formats_usage() {
+        let usage = ResourceUsage {
+            max_rss_bytes: 3 * 1024 * 1024,
+            user: Duration::from_millis(5),
+            system: Duration::from_micros(250),
+            wall: Duration::from_millis(6),
+        };
+        assert_eq!(
+            usage.to_string(),
+            "wall 6.0ms · user 5.0ms · sys 250.0µs · max RSS 3.0 MiB"
+        );
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_bytes("512"), Ok(512));
+        assert_eq!(parse_bytes("4K"), Ok(4096));
+        assert_eq!(parse_bytes("1.5MiB"), Ok(1_572_864));
+        assert_eq!(parse_bytes("2GB"), Ok(2 << 30));
+        assert!(parse_bytes("2 parsecs").is_err());
+        assert!(parse_bytes("GiB").is_err());
+    }
+
+    #[test]
+    fn parses_limits() {
+        let limits = Limits::parse(Some("30s"), Some("1GiB")).unwrap();
+        assert_eq!(limits.timeout, Some(Duration::from_secs(30)));
+        assert_eq!(limits.memory_bytes, Some(1 << 30));
+        assert!(Limits::parse(None, None).unwrap().is_unlimited());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reports_usage_of_children() {
+        let child = LimitedChild::spawn(&mut Command::new("true"), &Limits::default()).unwrap();
+        let exit = child.wait().unwrap();
+
+        assert!(exit.status.success());
+        assert!(!exit.is_timed_out);
+        let usage = exit.usage.unwrap();
+        assert!(usage.max_rss_bytes > 0);
+        assert!(usage.wall > Duration::ZERO);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn kills_children_after_their_timeout() {
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(100)),
+            ..Limits::default()
+        };
+        let started = Instant::now();
+        let child = LimitedChild::spawn(Command::new("sleep").arg("10"), &limits).unwrap();
+        let exit = child.wait().unwrap();
+
+        assert!(exit.is_timed_out);
+        assert!(!exit.status.success());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn limits_memory_of_children() {
+        let limits = Limits {
+            memory_bytes: Some(1 << 20),
+            ..Limits::default()
+        };
+        // a shell does not start with an address space of 1 MiB.
+        let child = LimitedChild::spawn(Command::new("sh").args(["-c", "true"]), &limits);
+        assert!(child.is_err() || !child.unwrap().wait().unwrap().status.success());
+    }
+}
@@ -1,48 +1,472 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, PuzzleId, Year,
+    alloc::format_bytes,
+    answers::Answers,
+    protocol::{PartRecord, Status},
+    resources::{Limits, ResourceUsage},
+    runner::print_record,
+    stats::format_nanos,
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Options that control how a set of days is run.
+#[derive(Clone, Debug)]
+pub struct MultiOptions {
+    pub is_release: bool,
+    pub is_timed: bool,
+    pub is_in_process: bool,
+    /// Number of days that run concurrently.
+    pub jobs: usize,
+    /// Timed runs are refused to run concurrently unless this is set, as benchmarks would compete for cores.
+    pub allow_parallel_timing: bool,
+    /// Limits of each day, only enforced for solution binaries.
+    pub limits: Limits,
+}
 
-    let mut need_space = false;
+/// The records collected while running a single day.
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub day: Day,
+    pub records: Vec<PartRecord>,
+    pub elapsed: Duration,
+    /// Resources used by the solution binary, not available for in-process runs.
+    pub usage: Option<ResourceUsage>,
+    /// Set if the day did not run to completion.
+    pub abort: Option<Abort>,
+    pub status: DayStatus,
+}
+
+/// The outcome of a day, ordered from best to worst.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DayStatus {
+    Solved,
+    /// Some parts have no answer yet.
+    Unsolved,
+    /// Some answers differ from the known answers, see [`Answers`].
+    Mismatched,
+    /// Some parts returned an error, or the day did not run to completion.
+    Failed,
+    Panicked,
+}
+
+impl DayStatus {
+    /// Every status, in the order of the results table.
+    const ALL: [DayStatus; 5] = [
+        DayStatus::Solved,
+        DayStatus::Unsolved,
+        DayStatus::Failed,
+        DayStatus::Panicked,
+        DayStatus::Mismatched,
+    ];
+
+    /// Returns the worst outcome of the parts of a day, comparing their answers against the known `answers`.
+    fn of(records: &[PartRecord], abort: Option<&Abort>, answers: &Answers) -> DayStatus {
+        if abort.is_some() {
+            return DayStatus::Failed;
+        }
+
+        records
+            .iter()
+            .map(|record| match (record.status, record.answer.as_deref()) {
+                (Status::Solved, Some(answer))
+                    if answers
+                        .get(record.puzzle(), record.part)
+                        .is_some_and(|expected| expected != answer) =>
+                {
+                    DayStatus::Mismatched
+                }
+                (Status::Solved, _) => DayStatus::Solved,
+                // a part that no longer returns its known answer regressed.
+                (Status::Unsolved, _) if answers.get(record.puzzle(), record.part).is_some() => {
+                    DayStatus::Mismatched
+                }
+                (Status::Unsolved, _) => DayStatus::Unsolved,
+                (Status::Failed, _) => DayStatus::Failed,
+                (Status::Panicked, _) => DayStatus::Panicked,
+            })
+            .max()
+            // days that have not been scaffolded yet produce no records.
+            .unwrap_or(DayStatus::Unsolved)
+    }
+
+    /// Unsolved days succeed, as they are simply not done yet.
+    pub fn is_success(self) -> bool {
+        matches!(self, DayStatus::Solved | DayStatus::Unsolved)
+    }
+}
+
+impl Display for DayStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            DayStatus::Solved => "solved",
+            DayStatus::Unsolved => "unsolved",
+            DayStatus::Mismatched => "mismatched",
+            DayStatus::Failed => "failed",
+            DayStatus::Panicked => "panicked",
+        })
+    }
+}
+
+/// Why a day did not run to completion.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Abort {
+    /// The solution binary was killed after the timeout.
+    TimedOut(Duration),
+    /// The memory limit in bytes was exceeded.
+    OutOfMemory(u64),
+    BuildFailed,
+    /// The solution exited unsuccessfully without a panicked part to blame, e.g. because its input is missing.
+    Crashed(String),
+}
+
+impl Display for Abort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Abort::TimedOut(timeout) => write!(
+                f,
+                "timed out after {}",
+                format_nanos(timeout.as_secs_f64() * 1e9)
+            ),
+            Abort::OutOfMemory(limit) => {
+                write!(f, "out of memory (limit {})", format_bytes(*limit))
+            }
+            Abort::BuildFailed => write!(f, "build failed"),
+            Abort::Crashed(reason) => write!(f, "crashed ({reason})"),
+        }
+    }
+}
+
+/// The result of running a set of days.
+#[derive(Clone, Debug, Default)]
+pub struct MultiRun {
+    pub days: Vec<DayRun>,
+    /// Benchmark times, only present for timed runs.
+    pub timings: Option<Timings>,
+}
+
+/// Runs a set of days of a single year, comparing their answers against the known `answers`.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: &MultiOptions,
+    answers: &Answers,
+) -> Result<MultiRun, Error> {
+    if options.is_timed && options.jobs > 1 && !options.allow_parallel_timing {
+        return Err(Error::ParallelTiming);
+    }
+
+    if options.is_in_process && !options.limits.is_unlimited() {
+        return Err(Error::InProcessLimits);
+    }
+
+    if options.is_in_process && options.is_release {
+        return Err(Error::InProcessRelease);
+    }
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days: Vec<Day> = all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+        .collect();
+    let is_parallel = options.jobs > 1 && days.len() > 1;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let mut timings: Vec<Timing> = Vec::with_capacity(days.len());
+    let mut summary: Vec<DayRun> = Vec::with_capacity(days.len());
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let mut need_space = false;
+    let mut print_header = |day: Day| {
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+    };
+
+    let mut collect = |day: Day, mut output: DayOutput, elapsed: Duration| {
+        retain_puzzle_parts(PuzzleId::new(year, day), &mut output.records);
 
-            if output.is_empty() {
+        if output.records.is_empty() {
+            // aborted days already printed why they have no records.
+            if output.abort.is_none() {
                 println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
             }
+        } else if options.is_timed {
+            let mut timing =
+                child_commands::parse_exec_time(&output.records, PuzzleId::new(year, day));
+            timing.usage = output.usage;
+            timings.push(timing);
+        }
+
+        summary.push(DayRun {
+            day,
+            status: DayStatus::of(&output.records, output.abort.as_ref(), answers),
+            records: output.records,
+            elapsed,
+            usage: output.usage,
+            abort: output.abort,
         });
+    };
 
-    if is_timed {
-        let timings = Timings { data: timings };
+    let timer = Instant::now();
+
+    if is_parallel {
+        if !options.is_in_process {
+            // build once up front, so that concurrent runs don't wait on each other for the build lock.
+            child_commands::build_solutions(options.is_release);
+        }
+
+        run_parallel(year, &days, options, |day, output, elapsed| {
+            print_header(day);
+            output.print();
+            collect(day, output, elapsed);
+        });
+    } else {
+        for &day in &days {
+            print_header(day);
+            let timer = Instant::now();
+            let output = run_day(PuzzleId::new(year, day), options, false);
+            collect(day, output, timer.elapsed());
+        }
+    }
+
+    if options.is_timed {
+        let timings = Timings {
+            data: timings,
+            history: vec![],
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
+        print_results(&summary);
+        Ok(MultiRun {
+            days: summary,
+            timings: Some(timings),
+        })
+    } else {
+        print_summary(
+            &summary,
+            timer.elapsed(),
+            if is_parallel { options.jobs } else { 1 },
+        );
+        print_results(&summary);
+        Ok(MultiRun {
+            days: summary,
+            timings: None,
+        })
+    }
+}
+
+/// Drops records of parts that the puzzle doesn't have, e.g. of a second part of the final day,
+/// so that they neither count towards the status of the day nor get benchmarked.
+fn retain_puzzle_parts(puzzle: PuzzleId, records: &mut Vec<PartRecord>) {
+    let part_count = puzzle.part_count();
+    records.retain(|record| record.part <= part_count);
+}
+
+/// Runs days on a pool of `options.jobs` worker threads.
+/// `on_done` is called in day order, as soon as a day and all days before it have finished.
+fn run_parallel(
+    year: Year,
+    days: &[Day],
+    options: &MultiOptions,
+    mut on_done: impl FnMut(Day, DayOutput, Duration),
+) {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(days.len()) {
+            let tx = tx.clone();
+            let next = &next;
+
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let timer = Instant::now();
+                    let output = run_day(PuzzleId::new(year, day), options, true);
+
+                    if tx.send((day, output, timer.elapsed())).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut remaining = days.iter().peekable();
+
+        for (day, output, elapsed) in rx {
+            pending.insert(day, (output, elapsed));
+
+            while let Some((output, elapsed)) = remaining.peek().and_then(|d| pending.remove(*d)) {
+                on_done(*remaining.next().unwrap(), output, elapsed);
+            }
+        }
+    });
+}
+
+fn run_day(puzzle: PuzzleId, options: &MultiOptions, is_buffered: bool) -> DayOutput {
+    if options.is_in_process {
+        in_process::run_solution(puzzle, options.is_timed, is_buffered)
     } else {
-        None
+        child_commands::run_solution(
+            puzzle,
+            options.is_timed,
+            options.is_release,
+            &options.limits,
+            is_buffered,
+        )
+        .unwrap_or_else(|e| {
+            let mut output = DayOutput::default();
+            output.abort(
+                Abort::Crashed(format!("could not run solution: {e}")),
+                is_buffered,
+            );
+            output
+        })
+    }
+}
+
+fn print_summary(summary: &[DayRun], elapsed: Duration, jobs: usize) {
+    println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("-------");
+
+    for DayRun {
+        day,
+        records,
+        elapsed,
+        usage,
+        abort,
+        ..
+    } in summary
+    {
+        let parts: Vec<&PartRecord> = records.iter().filter(|r| !r.is_parse()).collect();
+
+        // the usage of a solution binary includes its wall time.
+        let resources = match usage {
+            Some(usage) => usage.to_string(),
+            None => format_nanos(elapsed.as_secs_f64() * 1e9),
+        };
+
+        if let Some(abort) = abort {
+            println!("Day {day}: {abort} ({resources})");
+            continue;
+        }
+
+        if parts.is_empty() {
+            println!("Day {day}: not solved");
+            continue;
+        }
+
+        let count = |status| parts.iter().filter(|r| r.status == status).count();
+        let errors: String = [(Status::Failed, "failed"), (Status::Panicked, "panicked")]
+            .into_iter()
+            .filter_map(|(status, name)| match count(status) {
+                0 => None,
+                n => Some(format!(", {n} {name}")),
+            })
+            .collect();
+
+        println!(
+            "Day {day}: {}/{} parts solved{errors} ({resources})",
+            count(Status::Solved),
+            parts.len(),
+        );
+    }
+
+    println!(
+        "\n{ANSI_ITALIC}Ran {} days in {:.2}s using {jobs} job(s).{ANSI_RESET}",
+        summary.len(),
+        elapsed.as_secs_f64()
+    );
+}
+
+/// Prints how many days ended with each [`DayStatus`], so that failures of long runs are not missed.
+fn print_results(summary: &[DayRun]) {
+    println!("\n{ANSI_BOLD}Results{ANSI_RESET}");
+    println!("-------");
+
+    for status in DayStatus::ALL {
+        let days: Vec<String> = summary
+            .iter()
+            .filter(|run| run.status == status)
+            .map(|run| run.day.to_string())
+            .collect();
+
+        let line = format!("{status:<10}  {:>2}  {}", days.len(), days.join(", "));
+        println!("{}", line.trim_end());
+    }
+}
+
+/// A line of output produced while running a day.
+pub enum OutputLine {
+    Stdout(String),
+    Stderr(String),
+    Record(PartRecord),
+}
+
+/// Output of running a single day. When buffered, lines are kept until [`DayOutput::print`] is called,
+/// so that days running in parallel can be printed in order. Otherwise, lines are printed immediately.
+#[derive(Default)]
+pub struct DayOutput {
+    lines: Vec<OutputLine>,
+    pub records: Vec<PartRecord>,
+    pub usage: Option<ResourceUsage>,
+    pub abort: Option<Abort>,
+}
+
+impl DayOutput {
+    fn push(&mut self, line: OutputLine, is_buffered: bool) {
+        if let OutputLine::Record(record) = &line {
+            self.records.push(record.clone());
+        }
+
+        if is_buffered {
+            self.lines.push(line);
+        } else {
+            print_line(&line);
+        }
+    }
+
+    /// Marks the day as aborted and reports why.
+    fn abort(&mut self, abort: Abort, is_buffered: bool) {
+        self.push(
+            OutputLine::Stderr(format!("Aborted: {abort}.")),
+            is_buffered,
+        );
+        self.abort = Some(abort);
+    }
+
+    /// Print buffered output.
+    pub fn print(&self) {
+        self.lines.iter().for_each(print_line);
+    }
+}
+
+fn print_line(line: &OutputLine) {
+    match line {
+        OutputLine::Stdout(line) => println!("{line}"),
+        OutputLine::Stderr(line) => eprintln!("{line}"),
+        OutputLine::Record(record) => print_record(record),
     }
 }
 
@@ -51,6 +475,31 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    ParallelTiming,
+    InProcessLimits,
+    InProcessRelease,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not capture output of the solution."),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::ParallelTiming => write!(
+                f,
+                "refusing to time days in parallel, as benchmarks would compete for cores. \
+                Pass `--allow-parallel-timing` to do it anyway."
+            ),
+            Error::InProcessLimits => write!(
+                f,
+                "resource limits can only be enforced on solution binaries, not on in-process runs."
+            ),
+            Error::InProcessRelease => write!(
+                f,
+                "`--release` only applies to solution binaries, in-process runs use the profile of the runner itself."
+            ),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -60,133 +509,320 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{puzzle}.rs")
+}
+
+/// Solutions are also compiled into the library, see [`crate::solutions`].
+/// This module runs them inside the current process instead of spawning a binary per day.
+pub mod in_process {
+    use std::{
+        fs,
+        panic::{self, AssertUnwindSafe},
+    };
+
+    use super::{Abort, DayOutput, OutputLine};
+    use crate::{
+        solutions,
+        template::{
+            PuzzleId,
+            context::Context,
+            runner::{Report, RunOptions},
+        },
+    };
+
+    /// Run the registered solution for a given day.
+    /// Output that the solution prints itself can not be buffered and is written immediately.
+    pub fn run_solution(puzzle: PuzzleId, is_timed: bool, is_buffered: bool) -> DayOutput {
+        let mut output = DayOutput::default();
+
+        // skip days that have not been scaffolded yet.
+        let Some(solution) = solutions::get(puzzle) else {
+            return output;
+        };
+
+        let input_path = format!("data/{}/inputs/{}.txt", puzzle.year, puzzle.day);
+
+        let input = match fs::read_to_string(input_path) {
+            Ok(input) => input,
+            Err(e) => {
+                output.abort(
+                    Abort::Crashed(format!("could not open input file: {e}")),
+                    is_buffered,
+                );
+                return output;
+            }
+        };
+
+        let options = RunOptions {
+            is_timed,
+            // records are printed by the runner itself when running unbuffered.
+            report: if is_buffered {
+                Report::Silent
+            } else {
+                Report::Human
+            },
+            context: Context::real(),
+            ..RunOptions::default()
+        };
+
+        // the panic message is printed by the panic hook, keep going with the remaining days.
+        let Ok(records) =
+            panic::catch_unwind(AssertUnwindSafe(|| (solution.run)(&input, &options)))
+        else {
+            output.abort(Abort::Crashed("panicked".into()), is_buffered);
+            return output;
+        };
+
+        for record in records {
+            if is_buffered {
+                output.push(OutputLine::Record(record), true);
+            } else {
+                output.records.push(record);
+            }
+        }
+
+        output
+    }
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the result records they emit.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Abort, DayOutput, Error, OutputLine, get_path_for_bin};
+    use crate::template::{
+        PuzzleId,
+        protocol::{PARSE_PART, PartRecord, Status},
+        resources::{Exit, LimitedChild, Limits},
+        stats::format_nanos,
+    };
     use std::{
+        env::{self, consts::EXE_SUFFIX},
         io::{BufRead, BufReader},
-        path::Path,
+        path::{Path, PathBuf},
         process::{Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Features of the current build that are mirrored to the solution bins.
+    const FEATURES: &[&str] = if cfg!(feature = "alloc-stats") {
+        &["--features", "alloc-stats"]
+    } else {
+        &[]
+    };
+
+    /// Build all solution bins. Failures are reported by the runs of the affected days.
+    pub fn build_solutions(is_release: bool) {
+        let mut args = vec!["build", "--quiet", "--bins"];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        args.extend(FEATURES);
+
+        let _ = Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    }
+
+    /// Rust prints this when an allocation fails, before aborting the process.
+    const ALLOCATION_FAILURE: &str = "memory allocation of";
+
+    /// Run the solution bin for a given puzzle, killing it when it exceeds the limits of `options`.
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+        limits: &Limits,
+        is_buffered: bool,
+    ) -> Result<DayOutput, Error> {
+        let mut output = DayOutput::default();
+
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
+            return Ok(output);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = puzzle.to_string();
+        let mut build_args = vec!["build", "--quiet", "--bin", &bin_name];
 
         if is_release {
-            args.push("--release");
+            build_args.push("--release");
+        }
+
+        build_args.extend(FEATURES);
+
+        // build separately instead of using `cargo run`, so that the resources of the solution are
+        // measured without those of cargo.
+        let build = Command::new("cargo")
+            .args(&build_args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()?;
+
+        for line in String::from_utf8_lossy(&build.stderr).lines() {
+            output.push(OutputLine::Stderr(line.to_string()), is_buffered);
         }
 
+        if !build.status.success() {
+            output.abort(Abort::BuildFailed, is_buffered);
+            return Ok(output);
+        }
+
+        // ask the child for machine-readable result records.
+        let mut args = vec!["--json"];
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
-
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        // forward output to stdout/stderr while collecting result records from stdout.
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let mut cmd = LimitedChild::spawn(
+            Command::new(bin_path(&bin_name, is_release))
+                .args(&args)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped()),
+            limits,
+        )?;
 
-        let mut output = vec![];
+        let stdout = BufReader::new(cmd.child.stdout.take().ok_or(super::Error::BrokenPipe)?);
+        let stderr = BufReader::new(cmd.child.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
         let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
+            let mut lines = vec![];
+            let mut is_allocation_failure = false;
+
+            for line in stderr.lines() {
+                let line = line.unwrap();
+                is_allocation_failure |= line.contains(ALLOCATION_FAILURE);
+
+                if is_buffered {
+                    lines.push(line);
+                } else {
+                    eprintln!("{line}");
+                }
+            }
+
+            (lines, is_allocation_failure)
         });
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+
+            match PartRecord::from_line(&line) {
+                Some(record) if record.puzzle() == puzzle => {
+                    output.push(OutputLine::Record(record), is_buffered);
+                }
+                _ => output.push(OutputLine::Stdout(line), is_buffered),
+            }
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let (lines, is_allocation_failure) = thread.join().unwrap();
+
+        for line in lines {
+            output.push(OutputLine::Stderr(line), is_buffered);
+        }
+
+        let exit = cmd.wait()?;
+        output.usage = exit.usage;
+
+        // panicked parts are reported by their records, the bin only exits unsuccessfully to signal them.
+        let has_panicked = output.records.iter().any(|r| r.status == Status::Panicked);
+
+        if let Some(abort) = abort_of(&exit, limits, is_allocation_failure, has_panicked) {
+            output.abort(abort, is_buffered);
+        }
 
         Ok(output)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Tells why a solution bin was aborted, if it did not exit normally.
+    pub fn abort_of(
+        exit: &Exit,
+        limits: &Limits,
+        is_allocation_failure: bool,
+        has_panicked: bool,
+    ) -> Option<Abort> {
+        match (limits.timeout, limits.memory_bytes) {
+            (Some(timeout), _) if exit.is_timed_out => Some(Abort::TimedOut(timeout)),
+            // other crashes, e.g. segfaults, are reported as such even when a memory limit is set.
+            (_, Some(limit)) if is_allocation_failure || exit.is_killed_near(limit) => {
+                Some(Abort::OutOfMemory(limit))
+            }
+            _ if !exit.status.success() && !has_panicked => {
+                Some(Abort::Crashed(exit.status.to_string()))
+            }
+            _ => None,
+        }
+    }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+    /// Returns the path cargo builds a solution bin to, respecting `CARGO_TARGET_DIR`.
+    fn bin_path(bin_name: &str, is_release: bool) -> PathBuf {
+        let target_dir = env::var_os("CARGO_TARGET_DIR").unwrap_or_else(|| "target".into());
+        let profile = if is_release { "release" } else { "debug" };
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        PathBuf::from(target_dir)
+            .join(profile)
+            .join(format!("{bin_name}{EXE_SUFFIX}"))
+    }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+    pub fn parse_exec_time(records: &[PartRecord], puzzle: PuzzleId) -> super::Timing {
+        let mut timings = super::Timing::new(puzzle);
+
+        // only benched parts carry statistics, untimed runs do not produce timings.
+        for record in records.iter().filter(|r| r.puzzle() == puzzle) {
+            match record.status {
+                Status::Failed => {
+                    timings.failed_parts.push(record.part);
+                    continue;
+                }
+                Status::Panicked => {
+                    timings.panicked_parts.push(record.part);
+                    continue;
                 }
+                Status::Solved | Status::Unsolved => {}
+            }
 
-                timings.total_nanos += nanos;
-            });
+            if let Some(allocs) = record.allocs {
+                timings.allocs.insert(record.part, allocs);
+            }
 
-        timings
-    }
+            let Some(stats) = &record.stats else {
+                continue;
+            };
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+            if record.status != Status::Solved {
+                continue;
+            }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
+            let timing_str = format_nanos(record.nanos);
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+            match record.part {
+                PARSE_PART => {
+                    timings.parse = Some(timing_str);
+                    timings.parse_stats = Some(stats.clone());
+                }
+                1 => {
+                    timings.part_1 = Some(timing_str);
+                    timings.part_1_stats = Some(stats.clone());
+                }
+                2 => {
+                    timings.part_2 = Some(timing_str);
+                    timings.part_2_stats = Some(stats.clone());
+                }
+                _ => continue,
+            }
+
+            timings.total_nanos += record.nanos;
+        }
 
-        Some((str_timing, parsed_timing))
+        timings
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,53 +841,340 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
+        use std::time::Duration;
+
         use super::parse_exec_time;
 
-        use crate::day;
+        use crate::{
+            day,
+            template::{PuzzleId, protocol::PartRecord, stats::Stats},
+            year,
+        };
+
+        const PUZZLE: PuzzleId = PuzzleId::new(year!(2025), day!(1));
+
+        fn benched(nanos: &[u64]) -> Stats {
+            let samples: Vec<Duration> = nanos.iter().map(|x| Duration::from_nanos(*x)).collect();
+            Stats::from_samples(&samples).unwrap()
+        }
 
         #[test]
         fn parses_execution_times() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    PartRecord::new(PUZZLE, 1, Some("0".into()), benched(&[74, 74, 74])),
+                    PartRecord::new(
+                        PUZZLE,
+                        2,
+                        Some("10".into()),
+                        benched(&[74_130_000, 74_130_000]),
+                    ),
                 ],
-                day!(1),
+                PUZZLE,
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_1_stats.unwrap().samples, 3);
         }
 
         #[test]
         fn parses_with_patterns_in_input() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    PartRecord::new(
+                        PUZZLE,
+                        1,
+                        Some("@ @ @ ( ) ms (2s @ 5 samples)".into()),
+                        benched(&[2_000_000_000, 2_000_000_000]),
+                    ),
+                    PartRecord::new(
+                        PUZZLE,
+                        2,
+                        Some("10s".into()),
+                        benched(&[100_000_000, 100_000_000]),
+                    ),
                 ],
-                day!(1),
+                PUZZLE,
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
         fn parses_missing_parts() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    PartRecord::new(PUZZLE, 1, None, benched(&[10, 10])),
+                    PartRecord::new(PUZZLE, 2, None, benched(&[10, 10])),
                 ],
-                day!(1),
+                PUZZLE,
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_failed_parts() {
+            let res = parse_exec_time(
+                &[
+                    PartRecord::new(PUZZLE, 1, Some("0".into()), benched(&[500, 500])),
+                    PartRecord::failed(PUZZLE, 2, "no solution".into(), benched(&[10, 10])),
+                ],
+                PUZZLE,
+            );
+            assert_approx_eq!(res.total_nanos, 500_f64);
+            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.failed_parts, vec![2]);
+        }
+
+        #[test]
+        fn parses_panicked_parts() {
+            let res = parse_exec_time(
+                &[
+                    PartRecord::panicked(
+                        PUZZLE,
+                        1,
+                        "panicked".into(),
+                        Stats::single(Duration::ZERO),
+                    ),
+                    PartRecord::new(PUZZLE, 2, Some("0".into()), benched(&[500, 500])),
+                ],
+                PUZZLE,
+            );
+            assert_approx_eq!(res.total_nanos, 500_f64);
+            assert_eq!(res.part_1.is_none(), true);
+            assert_eq!(res.panicked_parts, vec![1]);
+            assert_eq!(res.failed_parts, Vec::<u8>::new());
+        }
+
+        #[test]
+        fn parses_parse_phase() {
+            let res = parse_exec_time(
+                &[
+                    PartRecord::parse(PUZZLE, benched(&[1_000, 1_000])),
+                    PartRecord::new(PUZZLE, 1, Some("0".into()), benched(&[500, 500])),
+                ],
+                PUZZLE,
+            );
+            assert_approx_eq!(res.total_nanos, 1500_f64);
+            assert_eq!(res.parse.unwrap(), "1.0µs");
+            assert_eq!(res.parse_stats.unwrap().samples, 2);
+            assert_eq!(res.part_1.unwrap(), "500.0ns");
+        }
+
+        #[test]
+        fn ignores_untimed_parts() {
+            let res = parse_exec_time(
+                &[PartRecord::new(
+                    PUZZLE,
+                    1,
+                    Some("0".into()),
+                    Stats::single(Duration::from_millis(1)),
+                )],
+                PUZZLE,
+            );
+            assert_approx_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.part_1.is_none(), true);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::HashSet, time::Duration};
+
+    use super::{
+        Abort, DayStatus, Error, MultiOptions, child_commands::abort_of, retain_puzzle_parts,
+        run_multi,
+    };
+    use crate::{
+        day,
+        template::{
+            PuzzleId,
+            answers::Answers,
+            protocol::PartRecord,
+            resources::{LimitedChild, Limits},
+            stats::Stats,
+        },
+        year,
+    };
+
+    fn timed_options(jobs: usize, allow_parallel_timing: bool) -> MultiOptions {
+        MultiOptions {
+            is_release: false,
+            is_timed: true,
+            is_in_process: true,
+            jobs,
+            allow_parallel_timing,
+            limits: Limits::default(),
+        }
+    }
+
+    #[test]
+    fn refuses_parallel_timing() {
+        let res = run_multi(
+            year!(2025),
+            &HashSet::new(),
+            &timed_options(4, false),
+            &Answers::default(),
+        );
+        assert!(matches!(res, Err(Error::ParallelTiming)));
+    }
+
+    #[test]
+    fn allows_explicit_parallel_timing() {
+        let res = run_multi(
+            year!(2025),
+            &HashSet::new(),
+            &timed_options(4, true),
+            &Answers::default(),
+        );
+        assert_eq!(res.unwrap().timings.unwrap().data.len(), 0);
+    }
+
+    #[test]
+    fn refuses_limits_for_in_process_runs() {
+        let options = MultiOptions {
+            limits: Limits {
+                timeout: Some(Duration::from_secs(1)),
+                ..Limits::default()
+            },
+            ..timed_options(1, false)
+        };
+        let res = run_multi(year!(2025), &HashSet::new(), &options, &Answers::default());
+        assert!(matches!(res, Err(Error::InProcessLimits)));
+    }
+
+    #[test]
+    fn refuses_release_for_in_process_runs() {
+        let options = MultiOptions {
+            is_release: true,
+            ..timed_options(1, false)
+        };
+        let res = run_multi(year!(2025), &HashSet::new(), &options, &Answers::default());
+        assert!(matches!(res, Err(Error::InProcessRelease)));
+    }
+
+    #[test]
+    fn classifies_days_by_their_worst_part() {
+        let puzzle = PuzzleId::new(year!(2025), day!(4));
+        let solved = |part, answer: &str| {
+            PartRecord::new(
+                puzzle,
+                part,
+                Some(answer.into()),
+                Stats::single(Duration::ZERO),
+            )
+        };
+
+        let mut answers = Answers::default();
+        answers.set(puzzle, 1, "42");
+
+        let status =
+            |records: &[PartRecord], abort: Option<&Abort>| DayStatus::of(records, abort, &answers);
+
+        assert_eq!(status(&[], None), DayStatus::Unsolved);
+        assert_eq!(
+            status(&[solved(1, "42"), solved(2, "7")], None),
+            DayStatus::Solved
+        );
+        assert_eq!(
+            status(
+                &[
+                    solved(1, "42"),
+                    PartRecord::new(puzzle, 2, None, Stats::single(Duration::ZERO))
+                ],
+                None
+            ),
+            DayStatus::Unsolved
+        );
+        assert_eq!(status(&[solved(1, "43")], None), DayStatus::Mismatched);
+        assert_eq!(
+            status(
+                &[PartRecord::new(
+                    puzzle,
+                    1,
+                    None,
+                    Stats::single(Duration::ZERO)
+                )],
+                None
+            ),
+            DayStatus::Mismatched
+        );
+        assert_eq!(
+            status(
+                &[
+                    PartRecord::panicked(
+                        puzzle,
+                        1,
+                        "panicked".into(),
+                        Stats::single(Duration::ZERO)
+                    ),
+                    PartRecord::failed(
+                        puzzle,
+                        2,
+                        "no solution".into(),
+                        Stats::single(Duration::ZERO)
+                    ),
+                ],
+                None
+            ),
+            DayStatus::Panicked
+        );
+        assert_eq!(
+            status(
+                &[solved(1, "42")],
+                Some(&Abort::Crashed("exit status: 1".into()))
+            ),
+            DayStatus::Failed
+        );
+        assert!(!DayStatus::Mismatched.is_success());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reports_crashes_below_the_memory_limit_as_crashed() {
+        let limits = Limits {
+            timeout: None,
+            memory_bytes: Some(256 << 20),
+        };
+
+        let abort = |signal: &str| {
+            let child = LimitedChild::spawn(
+                std::process::Command::new("sh").args(["-c", &format!("kill -{signal} $$")]),
+                &limits,
+            )
+            .unwrap();
+            abort_of(&child.wait().unwrap(), &limits, false, false)
+        };
+
+        assert!(matches!(abort("ABRT"), Some(Abort::Crashed(_))));
+        assert!(matches!(abort("SEGV"), Some(Abort::Crashed(_))));
+        // the shell is killed far below the memory limit.
+        assert!(matches!(abort("KILL"), Some(Abort::Crashed(_))));
+    }
+
+    #[test]
+    fn ignores_missing_parts_of_the_final_day() {
+        let puzzle = PuzzleId::new(year!(2025), day!(12));
+        let mut records = vec![
+            PartRecord::parse(puzzle, Stats::single(Duration::ZERO)),
+            PartRecord::new(puzzle, 1, Some("3".into()), Stats::single(Duration::ZERO)),
+            PartRecord::new(puzzle, 2, None, Stats::single(Duration::ZERO)),
+        ];
+
+        retain_puzzle_parts(puzzle, &mut records);
+
+        assert_eq!(
+            records.iter().map(|r| r.part).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert_eq!(
+            DayStatus::of(&records, None, &Answers::default()),
+            DayStatus::Solved
+        );
     }
 }
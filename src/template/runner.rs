@@ -1,63 +1,441 @@
 /// Encapsulates code that interacts with solution functions.
-use std::fmt::Display;
+use std::ffi::OsString;
 use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::io::{self, Write, stdout};
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::alloc::{self, AllocStats};
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{AocClient, AocError};
+use crate::template::context::{Context, parse_param};
+use crate::template::panics;
+use crate::template::protocol::{PARSE_PART, PartRecord, Status};
+use crate::template::stats::{Stats, format_nanos, parse_duration};
+use crate::template::submissions::{Submissions, Verdict};
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, PartOutput, PuzzleId, Solution, readme_stars,
+};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// How the runner reports the result of each part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Report {
+    /// Human-readable output, printed while the solution runs.
+    #[default]
+    Human,
+    /// A JSON line per part, see [`PartRecord`].
+    Json,
+    /// No output at all, records are only returned to the caller.
+    Silent,
+}
+
+/// Usage of the options that solution binaries accept, shown when they can not be parsed.
+const USAGE: &str = "Usage: cargo solve <day> [--part 1|2] [--input <path>|-] [--example [name]] [--time] \
+    [--iterations <n>] [--min-time <duration>] [--json] [--submit 1|2 [--force]] [--param <name>=<value>]...";
+
+/// Where a solution binary reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, e.g. `data/2025/inputs/08.txt`.
+    #[default]
+    Puzzle,
+    /// An example, `data/2025/examples/08.txt`, or `08-{name}.txt` if a name is given.
+    Example(Option<String>),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self, puzzle: PuzzleId) -> io::Result<String> {
+        let PuzzleId { year, day } = puzzle;
+
+        match self {
+            InputSource::Puzzle => fs::read_to_string(format!("data/{year}/inputs/{day}.txt")),
+            InputSource::Example(None) => {
+                fs::read_to_string(format!("data/{year}/examples/{day}.txt"))
+            }
+            InputSource::Example(Some(name)) => {
+                fs::read_to_string(format!("data/{year}/examples/{day}-{name}.txt"))
+            }
+            InputSource::Path(path) => fs::read_to_string(path),
+            InputSource::Stdin => io::read_to_string(io::stdin()),
+        }
+    }
+}
+
+/// Controls how many samples are taken when a part is benched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// A fixed number of measured iterations, takes precedence over `min_time`.
+    pub iterations: Option<u128>,
+    /// Measured iterations run for approx. this long, taking between 10 and 10000 samples.
+    pub min_time: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            iterations: None,
+            min_time: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Options that control how a solution is run.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub is_timed: bool,
+    pub bench: BenchOptions,
+    pub report: Report,
+    /// Only run this part, both parts run if unset.
+    pub part: Option<u8>,
+    pub input: InputSource,
+    /// The part that should be submitted after it was solved.
+    pub submit: Option<u8>,
+    /// Submit even if earlier submissions prove the answer to be wrong.
+    pub force_submit: bool,
+    /// The context that is passed to the parts, see [`Context`].
+    pub context: Context,
+}
+
+impl RunOptions {
+    /// Reads the options passed to a solution binary from the command line, exiting if they are invalid.
+    pub fn from_args() -> Self {
+        Self::parse(env::args_os().skip(1).collect()).unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            eprintln!("{USAGE}");
+            process::exit(1);
+        })
+    }
+
+    /// Parses the options of a solution binary, the program name must not be part of `args`.
+    pub fn parse(mut args: Vec<OsString>) -> Result<Self, String> {
+        // the name of an example is optional, which pico-args does not support.
+        let example = take_optional_value(&mut args, "--example");
+
+        let mut args = pico_args::Arguments::from_vec(args);
+        let error = |e: pico_args::Error| e.to_string();
+
+        let part: Option<u8> = args
+            .opt_value_from_fn("--part", parse_part)
+            .map_err(error)?;
+        let submit: Option<u8> = args
+            .opt_value_from_fn("--submit", parse_part)
+            .map_err(error)?;
+        let input_path: Option<String> = args.opt_value_from_str("--input").map_err(error)?;
+        let iterations: Option<u128> = args.opt_value_from_str("--iterations").map_err(error)?;
+        let min_time = args
+            .opt_value_from_fn("--min-time", parse_duration)
+            .map_err(error)?;
+        let params = args.values_from_fn("--param", parse_param).map_err(error)?;
+
+        let is_timed = args.contains("--time");
+        let is_json = args.contains("--json");
+        let force_submit = args.contains("--force");
+
+        if let Some(arg) = args.finish().first() {
+            return Err(format!("unexpected argument `{}`.", arg.to_string_lossy()));
+        }
+
+        let input = match (input_path, example) {
+            (Some(_), Some(_)) => {
+                return Err("`--input` and `--example` can not be combined.".into());
+            }
+            (Some(path), None) if path == "-" => InputSource::Stdin,
+            (Some(path), None) => InputSource::Path(PathBuf::from(path)),
+            (None, Some(name)) => InputSource::Example(name),
+            (None, None) => InputSource::Puzzle,
+        };
+
+        if let Some(submit) = submit {
+            if part.is_some_and(|part| part != submit) {
+                return Err(format!(
+                    "part {submit} can not be submitted when only running part {}.",
+                    part.unwrap_or_default()
+                ));
+            }
+
+            if matches!(input, InputSource::Example(_)) {
+                return Err("answers to examples can not be submitted.".into());
+            }
+        }
+
+        if iterations == Some(0) {
+            return Err("`--iterations` must be at least 1.".into());
+        }
+
+        let mut context = if matches!(input, InputSource::Example(_)) {
+            Context::example()
+        } else {
+            Context::real()
+        };
+
+        for (name, value) in params {
+            context = context.with_param(&name, &value);
+        }
+
+        Ok(Self {
+            is_timed,
+            bench: BenchOptions {
+                iterations,
+                min_time: min_time.unwrap_or(BenchOptions::default().min_time),
+            },
+            report: if is_json { Report::Json } else { Report::Human },
+            part,
+            input,
+            submit,
+            force_submit,
+            context,
+        })
+    }
+
+    /// Reads the input of a puzzle from the configured source, exiting if it can not be read.
+    pub fn read_input(&self, puzzle: PuzzleId) -> String {
+        self.input.read(puzzle).unwrap_or_else(|e| {
+            eprintln!("Error: could not open input file: {e}");
+            process::exit(1);
+        })
+    }
+
+    /// Returns the bench options if parts are timed.
+    fn bench(&self) -> Option<&BenchOptions> {
+        self.is_timed.then_some(&self.bench)
+    }
+
+    /// Checks whether a part should run.
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("expected part 1 or 2, got `{s}`.")),
+    }
+}
+
+/// Removes a flag along with its value if one follows, e.g. `--example` or `--example 2`.
+pub fn take_optional_value(args: &mut Vec<OsString>, flag: &str) -> Option<Option<String>> {
+    let index = args.iter().position(|arg| arg == flag)?;
+    args.remove(index);
+
+    let is_value = args
+        .get(index)
+        .and_then(|arg| arg.to_str())
+        .is_some_and(|arg| !arg.starts_with('-'));
+
+    Some(is_value.then(|| args.remove(index).to_string_lossy().into_owned()))
+}
+
+pub fn run_part<I: Copy, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+) -> PartRecord {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let timer = Instant::now();
+    let timed = panics::catch(|| {
+        run_timed(func, input, options.bench(), |result| {
+            if options.report == Report::Human {
+                let answer = result.to_answer();
+                print_result(borrow_answer(&answer), &part_str, "");
+
+                if options.is_timed {
+                    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                    let _ = stdout().flush();
+                }
+            }
+        })
+    });
+
+    let (result, stats, allocs) = match timed {
+        Ok(timed) => timed,
+        Err(panic) => {
+            let stats = Stats::single(timer.elapsed());
+            let record = PartRecord::panicked(puzzle, part, panic.to_string(), stats);
+            report_record(&record, options.report);
+            return record;
+        }
+    };
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let record = match result.to_answer() {
+        Ok(answer) => PartRecord::new(puzzle, part, answer, stats),
+        Err(error) => PartRecord::failed(puzzle, part, error, stats),
+    }
+    .with_allocs(allocs);
+    report_record(&record, options.report);
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Some(answer) = &record.answer
+        && options.submit == Some(part)
+    {
+        submit_result(answer, puzzle, part, options.force_submit);
     }
+
+    record
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run the parse phase of a [`Solution`] followed by the requested parts, timing each phase separately.
+pub fn run_parsed<S: Solution>(
+    input: &str,
+    puzzle: PuzzleId,
+    parts: &[u8],
+    options: &RunOptions,
+) -> Vec<PartRecord> {
+    let timer = Instant::now();
+    let timed = panics::catch(|| {
+        run_timed(S::parse, input, options.bench(), |_| {
+            if options.report == Report::Human {
+                print!("Parse:");
+
+                if options.is_timed {
+                    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                }
+
+                let _ = stdout().flush();
+            }
+        })
+    });
+
+    let (parsed, stats, allocs) = match timed {
+        Ok(timed) => timed,
+        Err(panic) => {
+            // the parts can not run without their parsed input.
+            let stats = Stats::single(timer.elapsed());
+            let record = PartRecord::panicked(puzzle, PARSE_PART, panic.to_string(), stats);
+            report_record(&record, options.report);
+            return vec![record];
+        }
+    };
+
+    let parse_record = PartRecord::parse(puzzle, stats).with_allocs(allocs);
+    report_record(&parse_record, options.report);
+
+    let mut total_nanos = parse_record.nanos;
+    let mut records = vec![parse_record];
+
+    for part in parts.iter().filter(|part| options.runs_part(**part)) {
+        let record = match part {
+            1 => run_part(S::part_one, &parsed, puzzle, 1, options),
+            2 => run_part(S::part_two, &parsed, puzzle, 2, options),
+            _ => continue,
+        };
+
+        total_nanos += record.nanos;
+        records.push(record);
+    }
+
+    if options.report == Report::Human {
+        println!("Total: {}", format_nanos(total_nanos));
+    }
+
+    records
+}
+
+fn report_record(record: &PartRecord, report: Report) {
+    match report {
+        Report::Human => print_record(record),
+        Report::Json => println!("{}", record.to_json_line()),
+        Report::Silent => {}
+    }
+}
+
+/// Print the human-readable form of a result record.
+pub fn print_record(record: &PartRecord) {
+    let part_str = if record.is_parse() {
+        "Parse".to_string()
+    } else {
+        format!("Part {}", record.part)
+    };
+
+    let duration_str = format_duration(record.nanos, record.samples);
+
+    if record.is_parse() && record.error.is_none() {
+        println!("\r{part_str}:{duration_str}             ");
+    } else {
+        let answer = match &record.error {
+            Some(error) => Err(error.as_str()),
+            None => Ok(record.answer.as_deref()),
+        };
+        print_result(answer, &part_str, &duration_str);
+    }
+
+    if let Some(stats) = &record.stats {
+        println!("{}", format_stats(stats));
+
+        if stats.is_noisy() {
+            eprintln!(
+                "{part_str}: high variance ({:.1}% relative standard deviation), timings may be unreliable.",
+                stats.relative_spread() * 100.0
+            );
+        }
+    }
+
+    if let Some(allocs) = &record.allocs {
+        println!("  ↳ {allocs}");
+    }
+}
+
+/// Exits with the code of a panicking process if any part panicked, after all parts ran.
+pub fn exit_if_panicked(records: &[PartRecord]) {
+    if records.iter().any(|r| r.status == Status::Panicked) {
+        process::exit(101);
+    }
+}
+
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. if not, the function is executed once.
+///  2. if it is, the function is benched according to `bench`, see [`BenchOptions`].
+///
+/// Timed parts also return the allocations of their first execution, if they are counted.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    bench_options: Option<&BenchOptions>,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Stats, Option<AllocStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, allocs) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        alloc::measure(|| func(input))
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let (stats, allocs) = match bench_options {
+        Some(options) => (bench(func, input, &base_time, options), allocs),
+        None => (Stats::single(base_time), None),
     };
 
-    (result, run.0, run.1)
+    (result, stats, allocs)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Bench a solution part. A number of warm-up iterations (approx. 100ms of execution time) is run and discarded
+/// before the measured iterations (a fixed number, or approx. `min_time` of execution time, between 10 and 10000 samples).
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    options: &BenchOptions,
+) -> Stats {
+    let base_nanos = cmp::max(base_time.as_nanos(), 10);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let warmup_iterations = (Duration::from_millis(100).as_nanos() / base_nanos).clamp(1, 1000);
+    let bench_iterations = options
+        .iterations
+        .unwrap_or_else(|| (options.min_time.as_nanos() / base_nanos).clamp(10, 10000));
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,35 +445,44 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers).unwrap_or_else(|| Stats::single(*base_time))
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+fn format_duration(nanos: f64, samples: u128) -> String {
+    let duration = format_nanos(nanos);
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
     if samples == 1 {
-        format!(" ({duration:.1?})")
+        format!(" ({duration})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({duration} @ {samples} samples)")
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "  ↳ min {} · mean {} ± {} · p95 {} · {} outliers",
+        format_nanos(stats.min_nanos),
+        format_nanos(stats.mean_nanos),
+        format_nanos(stats.std_dev_nanos),
+        format_nanos(stats.p95_nanos),
+        stats.outliers
+    )
+}
+
+fn borrow_answer(answer: &Result<Option<String>, String>) -> Result<Option<&str>, &str> {
+    answer
+        .as_ref()
+        .map(Option::as_deref)
+        .map_err(String::as_str)
+}
+
+/// Print the answer of a part, `Ok(None)` for unsolved parts and the error message for failed parts.
+fn print_result(result: Result<Option<&str>, &str>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -114,7 +501,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,46 +509,146 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(error) => {
+            if is_intermediate_result {
+                print!("{part}: ✖ {error}");
+            } else {
+                print!("\r");
+                println!("{part}: ✖ {error}{duration_str}             ");
+            }
+        }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Record an answer that was confirmed by a submission in the known-answer store.
+fn store_answer(puzzle: PuzzleId, part: u8, answer: &str) {
+    let mut answers = Answers::read_from_file();
+    answers.set(puzzle, part, answer);
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    match answers.store_file() {
+        Ok(()) => println!("Stored answer for {puzzle} part {part}."),
+        Err(e) => {
+            eprintln!("Failed to store answer: {e}");
+            return;
+        }
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    if readme_stars::update(&answers).is_err() {
+        eprintln!("Failed to update the stars in the readme.");
+    }
+}
+
+/// Submit one part of the solution to the website.
+/// Answers that earlier submissions prove to be wrong are refused, unless `force` is set.
+fn submit_result(answer: &str, puzzle: PuzzleId, part: u8, force: bool) {
+    let client = AocClient::from_env().unwrap_or_else(|e| {
+        eprintln!("Failed to create client: {e}");
         process::exit(1);
+    });
+
+    let mut submissions = Submissions::read_from_file();
+
+    if !force && let Err(refusal) = submissions.check(puzzle, part, answer) {
+        eprintln!("Refusing to submit `{answer}`: {refusal} Pass `--force` to submit anyway.");
+        return;
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    println!("Submitting result...");
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let verdict = match client.submit(puzzle, part, answer) {
+        Ok(message) => {
+            print!("{message}");
+            Verdict::Correct
+        }
+        Err(AocError::WrongAnswer(verdict)) => {
+            eprintln!("Error: {}", AocError::WrongAnswer(verdict));
+            verdict
+        }
+        // submissions that were not judged, e.g. due to rate limiting, are not logged.
+        Err(e) => {
+            eprintln!("Failed to submit answer: {e}");
+            return;
+        }
     };
 
-    if part_submit != part {
-        return None;
+    submissions.record(puzzle, part, answer, verdict);
+
+    if let Err(e) = submissions.store_file() {
+        eprintln!("Failed to store submission: {e}");
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+    if verdict == Verdict::Correct {
+        store_answer(puzzle, part, answer);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{ffi::OsString, path::PathBuf, time::Duration};
+
+    use super::{InputSource, Report, RunOptions};
+
+    fn parse(args: &[&str]) -> Result<RunOptions, String> {
+        RunOptions::parse(args.iter().map(OsString::from).collect())
+    }
+
+    #[test]
+    fn defaults_to_the_puzzle_input() {
+        let options = parse(&[]).unwrap();
+        assert_eq!(options.input, InputSource::Puzzle);
+        assert_eq!(options.report, Report::Human);
+        assert!(!options.context.is_example());
+        assert!(options.runs_part(1) && options.runs_part(2));
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn parses_options_in_any_order() {
+        let options = parse(&[
+            "--json",
+            "--submit",
+            "2",
+            "--time",
+            "--part",
+            "2",
+            "--min-time",
+            "500ms",
+            "--input",
+            "-",
+        ])
+        .unwrap();
+
+        assert_eq!(options.input, InputSource::Stdin);
+        assert_eq!(options.report, Report::Json);
+        assert_eq!(options.submit, Some(2));
+        assert_eq!(options.bench.min_time, Duration::from_millis(500));
+        assert!(options.is_timed);
+        assert!(!options.runs_part(1));
+    }
+
+    #[test]
+    fn parses_optional_example_names() {
+        let options = parse(&["--example", "--time"]).unwrap();
+        assert_eq!(options.input, InputSource::Example(None));
+        assert!(options.context.is_example());
+        assert!(options.is_timed);
+
+        let options = parse(&["--example", "2", "--iterations", "50"]).unwrap();
+        assert_eq!(options.input, InputSource::Example(Some("2".into())));
+        assert_eq!(options.bench.iterations, Some(50));
+
+        let options = parse(&["--input", "other.txt"]).unwrap();
+        assert_eq!(options.input, InputSource::Path(PathBuf::from("other.txt")));
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--part", "1", "--submit", "2"]).is_err());
+        assert!(parse(&["--example", "--submit", "1"]).is_err());
+        assert!(parse(&["--example", "--input", "a.txt"]).is_err());
+        assert!(parse(&["--iterations", "0"]).is_err());
+        assert!(parse(&["--unknown"]).is_err());
+    }
 }
@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+/// A solution that parses its input once and shares the result between both parts.
+///
+/// This is the opt-in alternative to free `part_one` / `part_two` functions that take the raw input.
+/// Register an implementation with `solution!(YEAR, DAY, Type)` to have the runner time the parse phase
+/// separately from the parts.
+///
+/// ```
+/// # use advent_of_code::template::Solution;
+/// pub struct Day01;
+///
+/// impl Solution for Day01 {
+///     type Input = Vec<u64>;
+///     type Answer1 = Option<u64>;
+///     type Answer2 = Result<u64, String>;
+///
+///     fn parse(input: &str) -> Self::Input {
+///         input.lines().filter_map(|l| l.parse().ok()).collect()
+///     }
+///
+///     fn part_one(input: &Self::Input) -> Self::Answer1 {
+///         input.iter().max().copied()
+///     }
+///
+///     fn part_two(input: &Self::Input) -> Self::Answer2 {
+///         input.iter().try_fold(0_u64, |sum, x| sum.checked_add(*x)).ok_or(String::from("overflow"))
+///     }
+/// }
+/// ```
+pub trait Solution {
+    /// The parsed representation of the puzzle input.
+    type Input;
+    /// The return value of the first part, e.g. `Option<u64>` or `Result<u64, String>`.
+    type Answer1: PartOutput;
+    /// The return value of the second part.
+    type Answer2: PartOutput;
+
+    fn parse(input: &str) -> Self::Input;
+
+    fn part_one(input: &Self::Input) -> Self::Answer1;
+
+    fn part_two(input: &Self::Input) -> Self::Answer2;
+}
+
+/// The return value of a solution part, either an `Option` or a `Result` of a displayable answer.
+///
+/// Returning `None` marks the part as unsolved. Returning an `Err` marks it as failed, the runner then shows
+/// the error message next to the part. Any error that implements [`Display`] works, including boxed errors.
+pub trait PartOutput {
+    /// Formats the answer, returning `Ok(None)` for unsolved parts and the error message for failed parts.
+    fn to_answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn to_answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn to_answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
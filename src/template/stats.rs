@@ -0,0 +1,222 @@
+/// Summary statistics over the samples collected while benching a solution part.
+use std::time::Duration;
+
+/// Samples further than this many inter-quartile ranges outside of the quartiles are rejected.
+const OUTLIER_IQR_FACTOR: f64 = 1.5;
+
+/// Relative standard deviation above which a benchmark is considered too noisy to trust.
+pub const NOISE_THRESHOLD: f64 = 0.1;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    /// Number of measured samples, including rejected outliers.
+    pub samples: u128,
+    /// Number of samples rejected as outliers.
+    pub outliers: u128,
+    /// The fastest measured sample, outliers are not rejected for it.
+    pub min_nanos: f64,
+    pub median_nanos: f64,
+    pub mean_nanos: f64,
+    pub std_dev_nanos: f64,
+    pub p95_nanos: f64,
+}
+
+impl Stats {
+    /// Computes statistics for a set of samples, rejecting outliers with Tukey's fences.
+    /// Returns [`None`] if `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        #[allow(clippy::cast_precision_loss)]
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+
+        if nanos.is_empty() {
+            return None;
+        }
+
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let fence = (q3 - q1) * OUTLIER_IQR_FACTOR;
+
+        let retained: Vec<f64> = nanos
+            .iter()
+            .copied()
+            .filter(|x| *x >= q1 - fence && *x <= q3 + fence)
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let count = retained.len() as f64;
+        let mean_nanos = retained.iter().sum::<f64>() / count;
+
+        let std_dev_nanos = if retained.len() > 1 {
+            let variance = retained
+                .iter()
+                .map(|x| (x - mean_nanos).powi(2))
+                .sum::<f64>()
+                / (count - 1.0);
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            samples: nanos.len() as u128,
+            outliers: (nanos.len() - retained.len()) as u128,
+            min_nanos: nanos[0],
+            median_nanos: percentile(&retained, 0.5),
+            mean_nanos,
+            std_dev_nanos,
+            p95_nanos: percentile(&retained, 0.95),
+        })
+    }
+
+    /// Creates statistics for a part that was only executed once.
+    pub fn single(duration: Duration) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = duration.as_nanos() as f64;
+
+        Self {
+            samples: 1,
+            outliers: 0,
+            min_nanos: nanos,
+            median_nanos: nanos,
+            mean_nanos: nanos,
+            std_dev_nanos: 0.0,
+            p95_nanos: nanos,
+        }
+    }
+
+    /// The standard deviation relative to the mean (coefficient of variation).
+    pub fn relative_spread(&self) -> f64 {
+        if self.mean_nanos == 0.0 {
+            0.0
+        } else {
+            self.std_dev_nanos / self.mean_nanos
+        }
+    }
+
+    /// Whether the spread of the samples is too high to trust the result.
+    pub fn is_noisy(&self) -> bool {
+        self.samples > 1 && self.relative_spread() > NOISE_THRESHOLD
+    }
+
+    pub fn median(&self) -> Duration {
+        to_duration(self.median_nanos)
+    }
+}
+
+/// Formats a duration given in nanoseconds the same way [`Duration`]'s debug output does.
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", to_duration(nanos))
+}
+
+/// Parses a duration such as `2s`, `1.5s`, `500ms`, `100us` or `10ns`. Plain numbers are seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let unit_start = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(unit_start);
+
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration `{s}`."))?;
+
+    let seconds = match unit {
+        "" | "s" => value,
+        "ms" => value / 1e3,
+        "us" | "µs" => value / 1e6,
+        "ns" => value / 1e9,
+        _ => return Err(format!("unknown unit `{unit}` in duration `{s}`.")),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|e| format!("invalid duration `{s}`: {e}."))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.max(0.0).round() as u64)
+}
+
+/// Linearly interpolated percentile of an ascending, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = p * (sorted.len() - 1) as f64;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+
+    #[allow(clippy::cast_precision_loss)]
+    let weight = rank - lower as f64;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Stats, parse_duration, percentile};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn interpolates_percentiles() {
+        let sorted = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(percentile(&sorted, 0.0), 1.0);
+        assert_eq!(percentile(&sorted, 0.5), 2.5);
+        assert_eq!(percentile(&sorted, 1.0), 4.0);
+    }
+
+    #[test]
+    fn computes_summary_statistics() {
+        let stats = Stats::from_samples(&nanos(&[10, 12, 11, 13, 14])).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.min_nanos, 10.0);
+        assert_eq!(stats.median_nanos, 12.0);
+        assert_eq!(stats.mean_nanos, 12.0);
+        assert!((stats.std_dev_nanos - 2.5_f64.sqrt()).abs() < 1e-9);
+
+        // a low outlier is excluded from the other statistics, but it still is the fastest sample.
+        let stats = Stats::from_samples(&nanos(&[100, 100, 101, 101, 102, 102, 2])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.min_nanos, 2.0);
+        assert_eq!(stats.median_nanos, 101.0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&nanos(&[10, 10, 11, 11, 12, 12, 500])).unwrap();
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median_nanos, 11.0);
+        assert!(!stats.is_noisy());
+    }
+
+    #[test]
+    fn flags_noisy_samples() {
+        let stats = Stats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        assert!(stats.is_noisy());
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("100us"), Ok(Duration::from_micros(100)));
+        assert!(parse_duration("2m").is_err());
+        assert!(parse_duration("ms").is_err());
+        assert!(parse_duration("100000000000000000000000000000s").is_err());
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -0,0 +1,376 @@
+/// A local log of submitted answers, used to refuse submissions that are known to be wrong
+/// before they cost a lockout on the Advent of Code website.
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, Year, year::parse_year};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// The verdict the website gave for a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Reads the verdict from the response to a submission.
+    /// Returns [`None`] if the answer was not judged, e.g. because the submission was rate limited.
+    pub fn from_response(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if response.contains("That's not the right answer") {
+            if response.contains("too high") {
+                Some(Verdict::TooHigh)
+            } else if response.contains("too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Wrong)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            s => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// The reason a submission is refused.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    AlreadyRejected,
+    NotBelow { bound: String },
+    NotAbove { bound: String },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "the part was already solved with `{answer}`.")
+            }
+            Refusal::AlreadyRejected => write!(f, "the answer was already rejected."),
+            Refusal::NotBelow { bound } => {
+                write!(f, "`{bound}` was too high and the answer is not lower.")
+            }
+            Refusal::NotAbove { bound } => {
+                write!(f, "`{bound}` was too low and the answer is not higher.")
+            }
+        }
+    }
+}
+
+/// Represents a single submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Represents the submitted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns no submissions.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(SUBMISSIONS_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(|s| Submissions::try_from(s.as_str()))
+            .unwrap_or_default()
+    }
+
+    pub fn record(&mut self, puzzle: PuzzleId, part: u8, answer: &str, verdict: Verdict) {
+        self.data.push(Submission {
+            year: puzzle.year,
+            day: puzzle.day,
+            part,
+            answer: answer.into(),
+            verdict,
+        });
+    }
+
+    /// Checks an answer against earlier submissions of the same part,
+    /// returning an error if the answer is provably wrong.
+    pub fn check(&self, puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), Refusal> {
+        let value = answer.trim().parse::<i128>().ok();
+
+        for submission in self
+            .data
+            .iter()
+            .filter(|s| s.year == puzzle.year && s.day == puzzle.day && s.part == part)
+        {
+            let bound = submission.answer.trim().parse::<i128>().ok();
+
+            match (submission.verdict, value.zip(bound)) {
+                (Verdict::Correct, _) => {
+                    return Err(Refusal::AlreadySolved {
+                        answer: submission.answer.clone(),
+                    });
+                }
+                _ if submission.answer == answer => return Err(Refusal::AlreadyRejected),
+                (Verdict::TooHigh, Some((value, bound))) if value >= bound => {
+                    return Err(Refusal::NotBelow {
+                        bound: submission.answer.clone(),
+                    });
+                }
+                (Verdict::TooLow, Some((value, bound))) if value <= bound => {
+                    return Err(Refusal::NotAbove {
+                        bound: submission.answer.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for Submissions {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).map_err(|x| x.to_string())?;
+        let json_data: &HashMap<String, JsonValue> = json
+            .get()
+            .ok_or("Expected submissions to be a JSON object.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .get("data")
+                .ok_or("Expected submissions to have key \"data\".")?
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected submissions.data to be an array.")?
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.to_string()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let year = parse_year(json, "submission")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        Ok(Submission {
+            year,
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Refusal, Submissions, Verdict};
+    use crate::{
+        day,
+        template::{Day, PuzzleId},
+        year,
+    };
+
+    fn puzzle(day: Day) -> PuzzleId {
+        PuzzleId::new(year!(2025), day)
+    }
+
+    fn submissions() -> Submissions {
+        let mut submissions = Submissions::default();
+        submissions.record(puzzle(day!(2)), 1, "100", Verdict::TooHigh);
+        submissions.record(puzzle(day!(2)), 1, "10", Verdict::TooLow);
+        submissions.record(puzzle(day!(2)), 1, "50", Verdict::Wrong);
+        submissions.record(puzzle(day!(2)), 2, "abc", Verdict::Wrong);
+        submissions.record(puzzle(day!(3)), 1, "7", Verdict::Correct);
+        submissions
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too high."),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer. If you're stuck, ..."),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently; you have to wait."),
+            None
+        );
+    }
+
+    #[test]
+    fn allows_plausible_answers() {
+        let submissions = submissions();
+        assert_eq!(submissions.check(puzzle(day!(2)), 1, "42"), Ok(()));
+        assert_eq!(submissions.check(puzzle(day!(2)), 2, "def"), Ok(()));
+        assert_eq!(submissions.check(puzzle(day!(4)), 1, "100"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_rejected_answers() {
+        let submissions = submissions();
+        assert_eq!(
+            submissions.check(puzzle(day!(2)), 1, "50"),
+            Err(Refusal::AlreadyRejected)
+        );
+        assert_eq!(
+            submissions.check(puzzle(day!(2)), 2, "abc"),
+            Err(Refusal::AlreadyRejected)
+        );
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let submissions = submissions();
+        assert_eq!(
+            submissions.check(puzzle(day!(2)), 1, "101"),
+            Err(Refusal::NotBelow {
+                bound: "100".into()
+            })
+        );
+        assert_eq!(
+            submissions.check(puzzle(day!(2)), 1, "1000"),
+            Err(Refusal::NotBelow {
+                bound: "100".into()
+            })
+        );
+        assert_eq!(
+            submissions.check(puzzle(day!(2)), 1, "-3"),
+            Err(Refusal::NotAbove { bound: "10".into() })
+        );
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        assert_eq!(
+            submissions().check(puzzle(day!(3)), 1, "8"),
+            Err(Refusal::AlreadySolved { answer: "7".into() })
+        );
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = submissions();
+        let json = JsonValue::from(&submissions).stringify().unwrap();
+        assert_eq!(Submissions::try_from(json.as_str()), Ok(submissions));
+    }
+}
@@ -1,16 +1,41 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::Error,
+    str::FromStr,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    Day, PuzzleId, Year,
+    alloc::AllocStats,
+    history::{HistoryEntry, ReadmePolicy, RunMeta},
+    resources::ResourceUsage,
+    stats::{Stats, format_nanos},
+    year::parse_year,
+};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
+    pub year: Year,
     pub day: Day,
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub parse_stats: Option<Stats>,
+    pub part_1_stats: Option<Stats>,
+    pub part_2_stats: Option<Stats>,
+    /// Parts that returned an error, they have no benchmark time.
+    pub failed_parts: Vec<u8>,
+    /// Parts that panicked, they have no benchmark time either.
+    pub panicked_parts: Vec<u8>,
+    /// Allocations of each phase, only collected with the `alloc-stats` feature.
+    pub allocs: BTreeMap<u8, AllocStats>,
+    /// Resources used by the solution binary, including the benchmark iterations.
+    pub usage: Option<ResourceUsage>,
     pub total_nanos: f64,
 }
 
@@ -18,14 +43,22 @@ pub struct Timing {
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
+    /// The latest timing of each day.
     pub data: Vec<Timing>,
+    /// Every stored run, oldest first.
+    pub history: Vec<HistoryEntry>,
 }
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
+        self.store_file_at(TIMINGS_FILE_PATH)
+    }
+
+    /// Dehydrate timings to a JSON file at a custom path.
+    pub fn store_file_at(&self, path: &str) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(path)?;
         json.format_to(&mut file)
     }
 
@@ -37,7 +70,7 @@ impl Timings {
             .unwrap_or_default()
     }
 
-    /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// Merge two sets of timings, overwriting `self` with `other` if present. The history of `new` is appended.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
@@ -46,13 +79,67 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle() == timing.puzzle()) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(Timing::puzzle);
+
+        let mut history = self.history.clone();
+        history.extend(new.history.iter().cloned());
+
+        Timings { data, history }
+    }
+
+    /// Appends the benched days of `new` to the history.
+    pub fn record_history(&mut self, new: &Self, meta: &RunMeta) {
+        for timing in new.data.iter().filter(|t| t.total_nanos > 0.0) {
+            self.history.push(HistoryEntry {
+                timing: timing.clone(),
+                meta: meta.clone(),
+            });
+        }
+    }
+
+    /// Returns the stored runs of a puzzle, oldest first.
+    pub fn history_of(&self, puzzle: PuzzleId) -> Vec<&HistoryEntry> {
+        self.history
+            .iter()
+            .filter(|entry| entry.puzzle() == puzzle)
+            .collect()
+    }
+
+    /// Returns the timings that are shown in the readme.
+    #[must_use]
+    pub fn for_readme(&self, policy: ReadmePolicy) -> Self {
+        match policy {
+            ReadmePolicy::Latest => self.clone(),
+            ReadmePolicy::Best => self.best(),
+        }
+    }
+
+    /// Returns the fastest run of each phase of every day. Days without history keep their latest timing.
+    #[must_use]
+    pub fn best(&self) -> Self {
+        let data = self
+            .data
+            .iter()
+            .map(|latest| {
+                let mut best = latest.clone();
+
+                for entry in self.history_of(latest.puzzle()) {
+                    best.keep_faster(&entry.timing);
+                }
+
+                best
+            })
+            .collect();
+
+        Timings {
+            data,
+            history: self.history.clone(),
+        }
     }
 
     /// Sum up total duration of timings as millis.
@@ -60,10 +147,140 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    /// Checks whether every part of a puzzle is benched, the final puzzle of a year only has a first part.
+    pub fn is_day_complete(&self, puzzle: PuzzleId) -> bool {
+        self.data.iter().any(|t| {
+            t.puzzle() == puzzle
+                && t.part_1.is_some()
+                && (t.part_2.is_some() || puzzle.part_count() == 1)
+        })
+    }
+
+    /// Returns the years that have timings, in ascending order.
+    pub fn years(&self) -> Vec<Year> {
+        let mut years: Vec<Year> = self.data.iter().map(|t| t.year).collect();
+        years.sort_unstable();
+        years.dedup();
+        years
+    }
+
+    /// Returns the timings of a single year.
+    #[must_use]
+    pub fn for_year(&self, year: Year) -> Self {
+        Timings {
+            data: self
+                .data
+                .iter()
+                .filter(|t| t.year == year)
+                .cloned()
+                .collect(),
+            history: self
+                .history
+                .iter()
+                .filter(|entry| entry.timing.year == year)
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl Timing {
+    /// An empty timing of a puzzle, without any times or statistics.
+    pub fn new(puzzle: PuzzleId) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            parse_stats: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            failed_parts: vec![],
+            panicked_parts: vec![],
+            allocs: BTreeMap::new(),
+            usage: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// A timing with the given times of both parts, for tests.
+    /// Set the remaining fields with the struct update syntax.
+    #[cfg(feature = "test_lib")]
+    pub fn test(year: Year, day: Day, part_1: Option<&str>, part_2: Option<&str>) -> Self {
+        Self {
+            part_1: part_1.map(Into::into),
+            part_2: part_2.map(Into::into),
+            ..Self::new(PuzzleId::new(year, day))
+        }
+    }
+
+    pub fn puzzle(&self) -> PuzzleId {
+        PuzzleId::new(self.year, self.day)
+    }
+
+    /// Returns the benchmark time of a part, or `failed` / `panicked` if the part did not produce an answer.
+    pub fn part_str(&self, part: u8) -> Option<&str> {
+        if self.failed_parts.contains(&part) {
+            return Some("failed");
+        }
+
+        if self.panicked_parts.contains(&part) {
+            return Some("panicked");
+        }
+
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the allocations of all phases combined, if they were counted.
+    pub fn total_allocs(&self) -> Option<AllocStats> {
+        self.allocs.values().copied().reduce(|a, b| a.add(&b))
+    }
+
+    /// Replaces the phases that `other` ran faster, recomputing the total.
+    fn keep_faster(&mut self, other: &Timing) {
+        let phases = [
+            (&mut self.parse, &mut self.parse_stats, &other.parse_stats),
+            (
+                &mut self.part_1,
+                &mut self.part_1_stats,
+                &other.part_1_stats,
+            ),
+            (
+                &mut self.part_2,
+                &mut self.part_2_stats,
+                &other.part_2_stats,
+            ),
+        ];
+
+        let mut changed = false;
+
+        for (time, stats, other_stats) in phases {
+            let Some(other_stats) = other_stats else {
+                continue;
+            };
+
+            if stats
+                .as_ref()
+                .is_none_or(|s| other_stats.median_nanos < s.median_nanos)
+            {
+                *time = Some(format_nanos(other_stats.median_nanos));
+                *stats = Some(other_stats.clone());
+                changed = true;
+            }
+        }
+
+        if changed {
+            self.total_nanos = [&self.parse_stats, &self.part_1_stats, &self.part_2_stats]
+                .into_iter()
+                .flatten()
+                .map(|s| s.median_nanos)
+                .sum();
+        }
     }
 }
 
@@ -77,6 +294,10 @@ impl From<Timings> for JsonValue {
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
 
         JsonValue::Object(map)
     }
@@ -88,19 +309,34 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        // the history is optional to stay compatible with timings stored by older versions.
+        let json_history = match json.get("history") {
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?
+                .as_slice(),
+            None => &[],
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
                 .map(Timing::try_from)
                 .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(HistoryEntry::try_from)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -111,6 +347,7 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
@@ -133,6 +370,79 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "parse_stats".into(),
+            value
+                .parse_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "failed_parts".into(),
+            JsonValue::Array(
+                value
+                    .failed_parts
+                    .iter()
+                    .map(|part| JsonValue::Number(f64::from(*part)))
+                    .collect(),
+            ),
+        );
+
+        map.insert(
+            "panicked_parts".into(),
+            JsonValue::Array(
+                value
+                    .panicked_parts
+                    .iter()
+                    .map(|part| JsonValue::Number(f64::from(*part)))
+                    .collect(),
+            ),
+        );
+
+        map.insert(
+            "allocs".into(),
+            JsonValue::Object(
+                value
+                    .allocs
+                    .iter()
+                    .map(|(part, allocs)| (part.to_string(), JsonValue::from(allocs)))
+                    .collect(),
+            ),
+        );
+
+        map.insert(
+            "usage".into(),
+            value
+                .usage
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -145,6 +455,8 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
+        let year = parse_year(json, "timing")?;
+
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
@@ -166,10 +478,73 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // the parse phase, statistics, failed parts, allocations and resource usage are optional to stay compatible with timings stored by older versions.
+        let parse = json.get("parse").and_then(|v| v.get::<String>()).cloned();
+
+        let parse_stats = match json.get("parse_stats") {
+            Some(v) if !v.is_null() => Some(Stats::try_from(v)?),
+            _ => None,
+        };
+
+        let part_1_stats = match json.get("part_1_stats") {
+            Some(v) if !v.is_null() => Some(Stats::try_from(v)?),
+            _ => None,
+        };
+
+        let part_2_stats = match json.get("part_2_stats") {
+            Some(v) if !v.is_null() => Some(Stats::try_from(v)?),
+            _ => None,
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let parts = |key: &str| -> Vec<u8> {
+            json.get(key)
+                .and_then(|v| v.get::<Vec<JsonValue>>())
+                .map(|parts| {
+                    parts
+                        .iter()
+                        .filter_map(|part| part.get::<f64>().map(|part| *part as u8))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let failed_parts = parts("failed_parts");
+        let panicked_parts = parts("panicked_parts");
+
+        let allocs = match json.get("allocs") {
+            Some(v) => v
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected timing.allocs to be an object.")?
+                .iter()
+                .map(|(part, allocs)| {
+                    let part = part.parse().map_err(|_| {
+                        format!("Expected timing.allocs key `{part}` to be a part.")
+                    })?;
+                    Ok((part, AllocStats::try_from(allocs)?))
+                })
+                .collect::<Result<_, String>>()?,
+            None => BTreeMap::new(),
+        };
+
+        let usage = match json.get("usage") {
+            Some(v) if !v.is_null() => Some(ResourceUsage::try_from(v)?),
+            _ => None,
+        };
+
         Ok(Timing {
+            year,
             day,
+            parse,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            parse_stats,
+            part_1_stats,
+            part_2_stats,
+            failed_parts,
+            panicked_parts,
+            allocs,
+            usage,
             total_nanos,
         })
     }
@@ -177,9 +552,61 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&Stats> for JsonValue {
+    fn from(value: &Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+            map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+        }
+
+        map.insert("min_nanos".into(), JsonValue::Number(value.min_nanos));
+        map.insert("median_nanos".into(), JsonValue::Number(value.median_nanos));
+        map.insert("mean_nanos".into(), JsonValue::Number(value.mean_nanos));
+        map.insert(
+            "std_dev_nanos".into(),
+            JsonValue::Number(value.std_dev_nanos),
+        );
+        map.insert("p95_nanos".into(), JsonValue::Number(value.p95_nanos));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Stats {
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+            min_nanos: number("min_nanos")?,
+            median_nanos: number("median_nanos")?,
+            mean_nanos: number("mean_nanos")?,
+            std_dev_nanos: number("std_dev_nanos")?,
+            p95_nanos: number("p95_nanos")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, year};
 
     use super::{Timing, Timings};
 
@@ -187,29 +614,24 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::test(year!(2025), day!(1), Some("10ms"), Some("20ms"))
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::test(year!(2025), day!(2), Some("30ms"), Some("40ms"))
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::test(year!(2025), day!(4), Some("40ms"), None)
                 },
             ],
+            history: vec![],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{day, template::timings::Timings, year};
 
         #[test]
         fn handles_json_timings() {
@@ -217,10 +639,65 @@ mod tests {
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
+            // timings without a year fall back to `AOC_YEAR`.
+            assert_eq!(timing.year, year!(2025));
             assert_eq!(timing.day, day!(1));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.part_1_stats, None);
+        }
+
+        #[test]
+        fn handles_json_timings_with_years() {
+            let json = r#"{ "data": [{ "year": "2024", "day": "25", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }, { "year": "2025", "day": "01", "part_1": "2ms", "part_2": "3ms", "total_nanos": 5000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.years(), vec![year!(2024), year!(2025)]);
+            let timing = &timings.data[0];
+            assert_eq!(timing.year, year!(2024));
+            assert_eq!(timing.day, day!(25));
+            assert_eq!(timings.for_year(year!(2025)).data.len(), 1);
+        }
+
+        #[test]
+        fn handles_json_statistics() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "samples": 100, "outliers": 2, "min_nanos": 900000, "median_nanos": 1000000, "mean_nanos": 1050000, "std_dev_nanos": 5000, "p95_nanos": 1200000 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let stats = timings.data[0].part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.samples, 100);
+            assert_eq!(stats.outliers, 2);
+            assert_eq!(stats.median_nanos, 1_000_000_f64);
+            assert_eq!(timings.data[0].part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_failed_parts() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "10", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "failed_parts": [2] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = &timings.data[0];
+            assert_eq!(timing.failed_parts, vec![2]);
+            assert_eq!(timing.part_str(1), Some("1ms"));
+            assert_eq!(timing.part_str(2), Some("failed"));
+        }
+
+        #[test]
+        fn handles_panicked_parts() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "05", "part_1": null, "part_2": "2ms", "total_nanos": 2000000, "panicked_parts": [1] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = &timings.data[0];
+            assert_eq!(timing.panicked_parts, vec![1]);
+            assert_eq!(timing.part_str(1), Some("panicked"));
+            assert_eq!(timing.part_str(2), Some("2ms"));
+        }
+
+        #[test]
+        fn handles_allocations() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "08", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000, "allocs": { "1": { "allocations": 3, "bytes": 2048, "peak_bytes": 1024 }, "2": { "allocations": 1, "bytes": 512, "peak_bytes": 512 } } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let allocs = timings.data[0].total_allocs().unwrap();
+            assert_eq!(allocs.allocations, 4);
+            assert_eq!(allocs.bytes, 2560);
+            assert_eq!(allocs.peak_bytes, 1024);
         }
 
         #[test]
@@ -271,56 +748,186 @@ mod tests {
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                PuzzleId,
+                timings::{Timing, Timings},
+            },
+            year,
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::test(year!(2025), day!(1), Some("1ms"), Some("2ms"))
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(
+                timings.is_day_complete(PuzzleId::new(year!(2025), day!(1))),
+                true
+            );
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::test(year!(2025), day!(1), Some("1ms"), None)
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(PuzzleId::new(year!(2025), day!(1))),
+                false
+            );
         }
 
         #[test]
-        fn handles_uncompleted_days() {
+        fn handles_single_part_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
+                    total_nanos: 1_000_000_000_f64,
+                    ..Timing::test(year!(2025), day!(12), Some("1ms"), None)
                 }],
+                history: vec![],
+            };
+
+            assert_eq!(
+                timings.is_day_complete(PuzzleId::new(year!(2025), day!(12))),
+                true
+            );
+        }
+
+        #[test]
+        fn handles_uncompleted_days() {
+            let timings = Timings {
+                data: vec![Timing::test(year!(2025), day!(1), None, None)],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(PuzzleId::new(year!(2025), day!(1))),
+                false
+            );
+        }
+    }
+
+    mod history {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                PuzzleId,
+                history::{ReadmePolicy, RunMeta},
+                stats::{Stats, format_nanos},
+                timings::{Timing, Timings},
+            },
+            year,
+        };
+
+        const PUZZLE: PuzzleId = PuzzleId::new(year!(2025), day!(9));
+
+        fn timing(part_1: u64, part_2: u64) -> Timing {
+            let stats = |nanos| Stats::from_samples(&[Duration::from_nanos(nanos); 2]).unwrap();
+
+            let (part_1_str, part_2_str) =
+                (format_nanos(part_1 as f64), format_nanos(part_2 as f64));
+
+            Timing {
+                part_1_stats: Some(stats(part_1)),
+                part_2_stats: Some(stats(part_2)),
+                total_nanos: (part_1 + part_2) as f64,
+                ..Timing::test(
+                    PUZZLE.year,
+                    PUZZLE.day,
+                    Some(&part_1_str),
+                    Some(&part_2_str),
+                )
+            }
+        }
+
+        fn meta(commit: &str) -> RunMeta {
+            RunMeta {
+                date: "2025-12-09T14:03:20Z".into(),
+                commit: Some(commit.into()),
+                is_dirty: false,
+                profile: "release".into(),
+            }
+        }
+
+        fn stored_runs() -> Timings {
+            let mut timings = Timings::default();
+
+            for (commit, run) in [("a", timing(10, 400)), ("b", timing(20, 300))] {
+                let run = Timings {
+                    data: vec![run],
+                    history: vec![],
+                };
+                timings = timings.merge(&run);
+                timings.record_history(&run, &meta(commit));
+            }
+
+            timings
+        }
+
+        #[test]
+        fn appends_every_stored_run() {
+            let timings = stored_runs();
+            let history = timings.history_of(PUZZLE);
+
+            assert_eq!(timings.data.len(), 1);
+            assert_eq!(history.len(), 2);
+            assert_eq!(history[0].meta.commit.as_deref(), Some("a"));
+            assert_eq!(history[1].timing.total_nanos, 320_f64);
+        }
+
+        #[test]
+        fn keeps_latest_or_best_parts_for_readme() {
+            let timings = stored_runs();
+
+            let latest = timings.for_readme(ReadmePolicy::Latest);
+            assert_eq!(latest.data[0].total_nanos, 320_f64);
+
+            let best = timings.for_readme(ReadmePolicy::Best);
+            assert_eq!(best.data[0].part_1.as_deref(), Some("10.0ns"));
+            assert_eq!(best.data[0].part_2.as_deref(), Some("300.0ns"));
+            assert_eq!(best.data[0].total_nanos, 310_f64);
+        }
+
+        #[test]
+        fn roundtrips_history() {
+            let timings = stored_runs();
+            let json = tinyjson::JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+
+            assert_eq!(parsed.history.len(), 2);
+            assert_eq!(parsed.history[1].meta, meta("b"));
+            assert_eq!(
+                parsed.history[1]
+                    .timing
+                    .part_2_stats
+                    .as_ref()
+                    .unwrap()
+                    .samples,
+                2
+            );
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                PuzzleId,
+                timings::{Timing, Timings},
+            },
+            year,
         };
 
         use super::get_mock_timings;
@@ -329,12 +936,8 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::test(year!(2025), day!(3), None, None)],
+                history: vec![],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,12 +952,8 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::test(year!(2025), day!(2), None, None)],
+                history: vec![],
             };
             let merged = timings.merge(&other);
 
@@ -365,6 +964,23 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_same_day_of_other_years() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing::test(year!(2024), day!(2), None, None)],
+                history: vec![],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].puzzle(), PuzzleId::new(year!(2024), day!(2)));
+            assert_eq!(merged.data[2].puzzle(), PuzzleId::new(year!(2025), day!(2)));
+            assert_eq!(merged.years(), vec![year!(2024), year!(2025)]);
+            assert_eq!(merged.for_year(year!(2025)).data.len(), 3);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
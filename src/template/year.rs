@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use tinyjson::JsonValue;
+
+use crate::template::{AllDays, Day};
+
+/// The year of the first Advent of Code event.
+const FIRST_YEAR: u16 = 2015;
+
+/// The number of puzzles per year, as `(year, day count)` pairs sorted by year.
+/// Each entry applies from its year until the year of the next entry.
+/// The final puzzle of every calendar has a single part, see [`Year::part_count`].
+const CALENDAR: &[(u16, u8)] = &[(FIRST_YEAR, 25), (2025, 12)];
+
+/// A year in which Advent of Code took place (i.e. an integer from 2015 onwards).
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the default year, configured with the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The puzzle calendar of a year.
+impl Year {
+    /// Returns the number of puzzles of this year, see [`CALENDAR`].
+    pub const fn day_count(self) -> u8 {
+        let mut count = CALENDAR[0].1;
+        let mut i = 1;
+
+        // `const fn`s can't use iterators yet.
+        while i < CALENDAR.len() && CALENDAR[i].0 <= self.0 {
+            count = CALENDAR[i].1;
+            i += 1;
+        }
+
+        count
+    }
+
+    /// Returns the day of the final puzzle of this year.
+    pub const fn last_day(self) -> Day {
+        match Day::new(self.day_count()) {
+            Some(day) => day,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns whether this year has a puzzle on the given day.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.day_count()
+    }
+
+    /// Returns the number of parts of the puzzle on the given day.
+    /// The final puzzle only has a single part, its second star is awarded for completing every other puzzle.
+    pub fn part_count(self, day: Day) -> u8 {
+        if day == self.last_day() { 1 } else { 2 }
+    }
+
+    /// Returns an iterator over the days of this year's calendar.
+    pub fn days(self) -> AllDays {
+        AllDays::new(self)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year from {FIRST_YEAR} onwards")
+    }
+}
+
+/// Reads the `year` key of a stored JSON object, where `name` describes the object in errors.
+/// Files written before years were tracked have no such key, their entries belong to the default year.
+pub(crate) fn parse_year(json: &HashMap<String, JsonValue>, name: &str) -> Result<Year, String> {
+    match json.get("year") {
+        Some(value) => value
+            .get::<String>()
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or_else(|| format!("Expected {name}.year to be a Year struct.")),
+        None => Year::from_env().ok_or_else(|| format!("Expected {name} to have key \"year\".")),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a puzzle by its year and day.
+///
+/// # Display
+/// This value displays as the year and the two digit day, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2025).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2025-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Creates a [`PuzzleId`] if the calendar of the year has a puzzle on the given day.
+    pub fn checked(year: Year, day: Day) -> Option<Self> {
+        year.has_day(day).then_some(Self { year, day })
+    }
+
+    /// Returns the number of parts of this puzzle, see [`Year::part_count`].
+    pub fn part_count(self) -> u8 {
+        self.year.part_count(self.day)
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle of the current day if it's part of this year's calendar, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        use chrono::{Datelike, FixedOffset, Utc};
+
+        let offset = FixedOffset::east_opt(crate::template::day::SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        Some(Self::new(year, Day::today()?))
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value from 2015 onwards")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, Year};
+    use crate::day;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2025".parse::<Year>().unwrap(), Year(2025));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("abc".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn calendar_length() {
+        assert_eq!(Year(2024).day_count(), 25);
+        assert_eq!(Year(2025).day_count(), 12);
+        assert_eq!(Year(2015).day_count(), 25);
+        assert_eq!(Year(2030).day_count(), 12);
+        assert!(Year(2025).has_day(day!(12)));
+        assert!(!Year(2025).has_day(day!(13)));
+        assert!(PuzzleId::checked(Year(2025), day!(13)).is_none());
+        assert_eq!(Year(2025).days().last(), Some(day!(12)));
+    }
+
+    #[test]
+    fn final_puzzle_has_one_part() {
+        assert_eq!(Year(2025).part_count(day!(11)), 2);
+        assert_eq!(Year(2025).part_count(day!(12)), 1);
+        assert_eq!(Year(2024).part_count(day!(12)), 2);
+        assert_eq!(Year(2024).part_count(day!(25)), 1);
+    }
+
+    #[test]
+    fn orders_puzzles_by_year_first() {
+        let a = PuzzleId::new(Year(2024), day!(25));
+        let b = PuzzleId::new(Year(2025), day!(1));
+        assert!(a < b);
+        assert_eq!(b.to_string(), "2025-01");
+    }
+}
@@ -0,0 +1,53 @@
+//! The registry of solutions is left out of the library's own test build, see `src/solutions/mod.rs`,
+//! so the in-process path is tested against the regular library build here.
+use advent_of_code::{
+    day, solutions,
+    template::{
+        PuzzleId,
+        context::Context,
+        protocol::{PARSE_PART, Status},
+        read_file,
+        runner::{Report, RunOptions},
+    },
+    year,
+};
+
+const PUZZLE: PuzzleId = PuzzleId::new(year!(2025), day!(1));
+
+#[test]
+fn registers_every_scaffolded_day() {
+    let puzzles: Vec<PuzzleId> = solutions::all().iter().map(|s| s.puzzle).collect();
+
+    assert_eq!(puzzles.first(), Some(&PUZZLE));
+    assert!(puzzles.is_sorted());
+    assert!(solutions::get(PuzzleId::new(year!(2015), day!(1))).is_none());
+}
+
+#[test]
+fn solves_parts_in_process() {
+    let solution = solutions::get(PUZZLE).unwrap();
+    let input = read_file("examples", PUZZLE);
+
+    let part_one = solution.part(1).unwrap();
+    assert_eq!(part_one(&input, &Context::example()), Ok(Some("3".into())));
+}
+
+#[test]
+fn runs_solutions_in_process() {
+    let solution = solutions::get(PUZZLE).unwrap();
+    let options = RunOptions {
+        report: Report::Silent,
+        context: Context::example(),
+        ..RunOptions::default()
+    };
+
+    let records = (solution.run)(&read_file("examples", PUZZLE), &options);
+    let answers: Vec<(u8, Option<&str>)> = records
+        .iter()
+        .filter(|r| r.part != PARSE_PART)
+        .map(|r| (r.part, r.answer.as_deref()))
+        .collect();
+
+    assert!(records.iter().all(|r| r.status == Status::Solved));
+    assert_eq!(answers, vec![(1, Some("3")), (2, Some("6"))]);
+}